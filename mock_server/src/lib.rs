@@ -1,3 +1,5 @@
+use std::fmt;
+
 use mockito::{Matcher, Server, ServerGuard};
 use serde::Serialize;
 
@@ -5,13 +7,15 @@ use serde::Serialize;
 pub enum RequestMethod {
     Get,
     Post,
+    Patch,
 }
 
-impl ToString for RequestMethod {
-    fn to_string(&self) -> String {
+impl fmt::Display for RequestMethod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            RequestMethod::Get => "GET".to_string(),
-            RequestMethod::Post => "POST".to_string(),
+            RequestMethod::Get => write!(f, "GET"),
+            RequestMethod::Post => write!(f, "POST"),
+            RequestMethod::Patch => write!(f, "PATCH"),
         }
     }
 }
@@ -36,7 +40,7 @@ fn build_file_path_for_response<T: Serialize>(
                 format!("mock_server/responses/{}/{}.json", endpoint, status)
             }
         }
-        RequestMethod::Post => {
+        RequestMethod::Post | RequestMethod::Patch => {
             format!("mock_server/responses/{}/{}.json", endpoint, status)
         }
     }
@@ -53,12 +57,25 @@ impl MockServerBuilder {
     }
 
     pub async fn mock_response<T: Serialize>(
+        self,
+        method: RequestMethod,
+        endpoint: &str,
+        status: usize,
+        bearer_token: Option<String>,
+        body_or_query: Option<&T>,
+    ) -> Self {
+        self.mock_response_with_headers(method, endpoint, status, bearer_token, body_or_query, &[])
+            .await
+    }
+
+    pub async fn mock_response_with_headers<T: Serialize>(
         mut self,
         method: RequestMethod,
         endpoint: &str,
         status: usize,
         bearer_token: Option<String>,
         body_or_query: Option<&T>,
+        headers: &[(&str, &str)],
     ) -> Self {
         let body_response = build_file_path_for_response(&method, endpoint, status, &body_or_query);
 
@@ -72,6 +89,10 @@ impl MockServerBuilder {
             .with_status(status)
             .with_body_from_file(&body_response);
 
+        for (name, value) in headers {
+            mock = mock.with_header(*name, value);
+        }
+
         if let Some(bearer_token) = bearer_token {
             mock = mock.match_header("Authorization", format!("Bearer {}", bearer_token).as_str());
         }
@@ -84,7 +105,7 @@ impl MockServerBuilder {
                     mock = mock.match_query(query_string.as_str());
                 }
             }
-            RequestMethod::Post => {
+            RequestMethod::Post | RequestMethod::Patch => {
                 if let Some(body) = body_or_query {
                     let json = serde_json::to_value(body).expect("Failed to serialize body");
                     mock = mock.match_body(Matcher::Json(json));
@@ -0,0 +1,256 @@
+use std::collections::HashSet;
+
+use serde::de::DeserializeOwned;
+use serde_derive::Deserialize;
+
+use crate::{
+    contract::ContractData, ship::ShipConditionEvent, system::waypoint::market::MarketTransaction,
+};
+
+const SHIP_CONDITION: &str = "SHIP_CONDITION";
+const CONTRACT_PREFIX: &str = "CONTRACT_";
+const MARKET_TRANSACTION: &str = "MARKET_TRANSACTION";
+
+#[derive(Debug, PartialEq)]
+pub enum AgentEvent {
+    ShipCondition {
+        ship_symbol: String,
+        event: ShipConditionEvent,
+    },
+    Contract {
+        kind: String,
+        contract: ContractData,
+    },
+    MarketTransaction(MarketTransaction),
+    Unknown {
+        kind: String,
+        data: serde_json::Value,
+    },
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+#[serde(from = "RawAgentEvent")]
+pub struct AgentEventEntry {
+    pub id: String,
+    pub message: String,
+    pub created_at: String,
+    pub event: AgentEvent,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawAgentEvent {
+    id: String,
+    #[serde(rename = "type")]
+    kind: String,
+    message: String,
+    #[serde(default)]
+    data: serde_json::Value,
+    created_at: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ShipConditionEventData {
+    ship_symbol: String,
+    #[serde(flatten)]
+    event: ShipConditionEvent,
+}
+
+fn parse<T: DeserializeOwned>(data: &serde_json::Value) -> Option<T> {
+    serde_json::from_value(data.clone()).ok()
+}
+
+impl AgentEvent {
+    // Event data that does not match the shape expected for its kind is kept as raw JSON so a
+    // change on the server never makes the whole feed unreadable.
+    fn from_kind(kind: String, data: serde_json::Value) -> Self {
+        let event = if kind == SHIP_CONDITION {
+            parse::<ShipConditionEventData>(&data).map(|data| AgentEvent::ShipCondition {
+                ship_symbol: data.ship_symbol,
+                event: data.event,
+            })
+        } else if kind.starts_with(CONTRACT_PREFIX) {
+            parse(&data).map(|contract| AgentEvent::Contract {
+                kind: kind.clone(),
+                contract,
+            })
+        } else if kind == MARKET_TRANSACTION {
+            parse(&data).map(AgentEvent::MarketTransaction)
+        } else {
+            None
+        };
+
+        event.unwrap_or(AgentEvent::Unknown { kind, data })
+    }
+}
+
+impl From<RawAgentEvent> for AgentEventEntry {
+    fn from(raw: RawAgentEvent) -> Self {
+        AgentEventEntry {
+            id: raw.id,
+            message: raw.message,
+            created_at: raw.created_at,
+            event: AgentEvent::from_kind(raw.kind, raw.data),
+        }
+    }
+}
+
+// Remembers the ids seen at the newest timestamp, so events sharing that timestamp that arrive
+// on a later poll are still returned.
+#[derive(Debug, Default, PartialEq)]
+pub(crate) struct EventCursor {
+    created_at: Option<String>,
+    ids: HashSet<String>,
+}
+
+impl EventCursor {
+    pub(crate) fn unseen(&mut self, mut events: Vec<AgentEventEntry>) -> Vec<AgentEventEntry> {
+        events.retain(|event| match &self.created_at {
+            Some(created_at) => {
+                event.created_at > *created_at
+                    || (event.created_at == *created_at && !self.ids.contains(&event.id))
+            }
+            None => true,
+        });
+
+        events.sort_by(|a, b| a.created_at.cmp(&b.created_at));
+
+        if let Some(newest) = events.last() {
+            if self.created_at.as_ref() != Some(&newest.created_at) {
+                self.created_at = Some(newest.created_at.clone());
+                self.ids.clear();
+            }
+
+            self.ids.extend(
+                events
+                    .iter()
+                    .filter(|event| event.created_at == newest.created_at)
+                    .map(|event| event.id.clone()),
+            );
+        }
+
+        events
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use crate::{
+        ship::{ShipComponent, ShipConditionEventSymbol},
+        string,
+    };
+
+    #[test]
+    fn ship_condition_event_should_be_deserializable() {
+        let json = r#"{
+            "id": "1",
+            "type": "SHIP_CONDITION",
+            "message": "Coolant leak",
+            "data": {
+                "shipSymbol": "BADGER-1",
+                "symbol": "COOLANT_LEAK",
+                "component": "REACTOR",
+                "name": "Coolant Leak",
+                "description": "A leak in the coolant system."
+            },
+            "createdAt": "2025-06-01T10:15:30.000Z"
+        }"#;
+
+        let actual: AgentEventEntry = serde_json::from_str(json).unwrap();
+
+        let expected = AgentEvent::ShipCondition {
+            ship_symbol: string!("BADGER-1"),
+            event: ShipConditionEvent {
+                symbol: ShipConditionEventSymbol::CoolantLeak,
+                component: ShipComponent::Reactor,
+                name: string!("Coolant Leak"),
+                description: string!("A leak in the coolant system."),
+            },
+        };
+
+        assert_eq!(expected, actual.event);
+    }
+
+    #[test]
+    fn unknown_event_kind_should_keep_raw_json() {
+        let json = r#"{
+            "id": "1",
+            "type": "FACTION_REPUTATION",
+            "message": "Reputation increased",
+            "data": {"faction": "COSMIC", "reputation": 12},
+            "createdAt": "2025-06-01T10:18:00.000Z"
+        }"#;
+
+        let actual: AgentEventEntry = serde_json::from_str(json).unwrap();
+
+        let expected = AgentEvent::Unknown {
+            kind: string!("FACTION_REPUTATION"),
+            data: serde_json::json!({"faction": "COSMIC", "reputation": 12}),
+        };
+
+        assert_eq!(expected, actual.event);
+    }
+
+    #[test]
+    fn known_event_kind_with_unexpected_data_should_keep_raw_json() {
+        let json = r#"{
+            "id": "1",
+            "type": "MARKET_TRANSACTION",
+            "message": "Sold something",
+            "data": {"units": 20},
+            "createdAt": "2025-06-01T10:18:00.000Z"
+        }"#;
+
+        let actual: AgentEventEntry = serde_json::from_str(json).unwrap();
+
+        let expected = AgentEvent::Unknown {
+            kind: string!("MARKET_TRANSACTION"),
+            data: serde_json::json!({"units": 20}),
+        };
+
+        assert_eq!(expected, actual.event);
+    }
+
+    fn event(id: &str, created_at: &str) -> AgentEventEntry {
+        AgentEventEntry {
+            id: string!(id),
+            message: string!("Reputation increased"),
+            created_at: string!(created_at),
+            event: AgentEvent::Unknown {
+                kind: string!("FACTION_REPUTATION"),
+                data: serde_json::Value::Null,
+            },
+        }
+    }
+
+    #[test]
+    fn cursor_should_return_new_events_sharing_the_last_timestamp() {
+        let mut cursor = EventCursor::default();
+
+        let first = cursor.unseen(vec![
+            event("2", "2025-06-01T10:18:00.000Z"),
+            event("1", "2025-06-01T10:15:30.000Z"),
+        ]);
+        let second = cursor.unseen(vec![
+            event("1", "2025-06-01T10:15:30.000Z"),
+            event("2", "2025-06-01T10:18:00.000Z"),
+            event("3", "2025-06-01T10:18:00.000Z"),
+        ]);
+        let third = cursor.unseen(vec![
+            event("3", "2025-06-01T10:18:00.000Z"),
+            event("4", "2025-06-01T10:19:00.000Z"),
+        ]);
+
+        assert_eq!(
+            vec![
+                event("1", "2025-06-01T10:15:30.000Z"),
+                event("2", "2025-06-01T10:18:00.000Z")
+            ],
+            first
+        );
+        assert_eq!(vec![event("3", "2025-06-01T10:18:00.000Z")], second);
+        assert_eq!(vec![event("4", "2025-06-01T10:19:00.000Z")], third);
+    }
+}
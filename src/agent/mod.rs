@@ -1,13 +1,20 @@
 use core::str;
 use std::{collections::HashMap, sync::Arc};
 
-use serde_derive::Deserialize;
+use futures::{Stream, TryStreamExt};
+use serde_derive::{Deserialize, Serialize};
+
+mod events;
+pub use events::*;
 
 use crate::{
     account::RegistrationResponseData,
     contract::{Contract, ContractData},
     faction::Factions,
-    space_traders_client::{Error, SpaceTradersClient},
+    ship::{Ship, ShipData, TradeSymbol},
+    space_traders_client::{Error, Page, SpaceTradersClient, MAX_PAGE_LIMIT},
+    string,
+    system::waypoint::shipyard::{ShipType, ShipyardTransaction},
 };
 
 #[derive(Debug, Deserialize, PartialEq)]
@@ -21,14 +28,48 @@ pub struct AgentData {
     pub ship_count: Option<i32>,
 }
 
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PublicAgentData {
+    pub symbol: String,
+    pub headquarters: String,
+    pub credits: i64,
+    pub starting_faction: Factions,
+    pub ship_count: i32,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct PublicAgentDataResponse {
+    data: PublicAgentData,
+}
+
 #[derive(Debug, Deserialize, PartialEq)]
 pub struct AgentDataResponse {
     data: AgentData,
 }
 
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PurchaseShipRequest<'a> {
+    ship_type: ShipType,
+    waypoint_symbol: &'a str,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct PurchaseShipResponseData {
+    agent: AgentData,
+    ship: ShipData,
+    transaction: ShipyardTransaction,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct PurchaseShipResponse {
+    data: PurchaseShipResponseData,
+}
+
 #[derive(Debug, PartialEq, Deserialize)]
-pub struct ContractDataResponse {
-    data: Vec<ContractData>,
+struct AgentEventsResponse {
+    data: Vec<AgentEventEntry>,
 }
 
 #[derive(Debug, PartialEq)]
@@ -36,12 +77,15 @@ pub struct Agent {
     client: Arc<SpaceTradersClient>,
     pub data: AgentData,
     contracts: HashMap<String, Contract>,
+    ships: HashMap<String, Ship>,
+    event_cursor: EventCursor,
 }
 
 impl Agent {
     pub async fn new(client: Arc<SpaceTradersClient>) -> Result<Self, Error> {
         let agent_data = Agent::get_agent_data(&client).await?;
         let contract_data = Agent::get_contracts_data(&client).await?;
+        let ships = Agent::list_ships(&client).await?;
 
         let contracts: HashMap<String, Contract> = contract_data
             .into_iter()
@@ -49,9 +93,11 @@ impl Agent {
             .collect();
 
         Ok(Agent {
-            client: client,
+            client,
             data: agent_data,
-            contracts: contracts,
+            contracts,
+            ships: Agent::ships_by_symbol(ships),
+            event_cursor: EventCursor::default(),
         })
     }
 
@@ -70,7 +116,14 @@ impl Agent {
                 data.contract.id.clone(),
                 Contract::new(client.clone(), data.contract),
             )]),
+            ships: Agent::ships_by_symbol(
+                data.ships
+                    .into_iter()
+                    .map(|data| Ship::with_data(client.clone(), data))
+                    .collect(),
+            ),
             data: data.agent,
+            event_cursor: EventCursor::default(),
         }
     }
 
@@ -86,6 +139,91 @@ impl Agent {
         self.contracts.get_mut(id).unwrap()
     }
 
+    pub async fn accept_contract(&mut self, id: &str) -> Result<(), Error> {
+        let endpoint = format!("my/contracts/{}/accept", id);
+        self.data = known(&mut self.contracts, id, &endpoint)?.accept().await?;
+
+        Ok(())
+    }
+
+    pub async fn deliver_contract(
+        &mut self,
+        id: &str,
+        ship_symbol: &str,
+        trade_symbol: TradeSymbol,
+        units: i32,
+    ) -> Result<(), Error> {
+        let endpoint = format!("my/contracts/{}/deliver", id);
+        let contract = known(&mut self.contracts, id, &endpoint)?;
+        let ship = known(&mut self.ships, ship_symbol, &endpoint)?;
+
+        contract.deliver(ship, trade_symbol, units).await
+    }
+
+    pub async fn fulfill_contract(&mut self, id: &str) -> Result<(), Error> {
+        let endpoint = format!("my/contracts/{}/fulfill", id);
+        self.data = known(&mut self.contracts, id, &endpoint)?.fulfill().await?;
+
+        Ok(())
+    }
+
+    pub async fn negotiate_contract(&mut self, ship_symbol: &str) -> Result<&Contract, Error> {
+        let endpoint = format!("my/ships/{}/negotiate/contract", ship_symbol);
+        let contract = known(&mut self.ships, ship_symbol, &endpoint)?
+            .negotiate_contract()
+            .await?;
+        let id = contract.data.id.clone();
+
+        Ok(self.contracts.entry(id).or_insert(contract))
+    }
+
+    pub fn ships(&self) -> std::collections::hash_map::Iter<'_, String, Ship> {
+        self.ships.iter()
+    }
+
+    pub fn edit_ship(&mut self, symbol: &str) -> Option<&mut Ship> {
+        self.ships.get_mut(symbol)
+    }
+
+    fn ships_by_symbol(ships: Vec<Ship>) -> HashMap<String, Ship> {
+        ships
+            .into_iter()
+            .map(|ship| (ship.symbol().to_string(), ship))
+            .collect()
+    }
+
+    pub async fn purchase_ship(
+        &mut self,
+        ship_type: ShipType,
+        waypoint_symbol: &str,
+    ) -> Result<ShipyardTransaction, Error> {
+        let request = PurchaseShipRequest {
+            ship_type,
+            waypoint_symbol,
+        };
+
+        let response: PurchaseShipResponse = self
+            .client
+            .post_with_body("my/ships", &request, reqwest::StatusCode::CREATED)
+            .await?;
+
+        self.data = response.data.agent;
+        self.ships.insert(
+            response.data.ship.symbol.clone(),
+            Ship::with_data(self.client.clone(), response.data.ship),
+        );
+
+        Ok(response.data.transaction)
+    }
+
+    pub async fn list_ships(client: &Arc<SpaceTradersClient>) -> Result<Vec<Ship>, Error> {
+        client
+            .paginate(string!("my/ships"), None::<()>, MAX_PAGE_LIMIT)
+            .map_ok(|data: ShipData| Ship::with_data(client.clone(), data))
+            .try_collect()
+            .await
+    }
+
     pub async fn get_agent_data(client: &SpaceTradersClient) -> Result<AgentData, Error> {
         let response: AgentDataResponse = client
             .get("my/agent", None::<&()>, reqwest::StatusCode::OK)
@@ -94,26 +232,85 @@ impl Agent {
         Ok(response.data)
     }
 
-    pub async fn get_contracts_data(
+    pub fn list_agents(
         client: &SpaceTradersClient,
-    ) -> Result<Vec<ContractData>, Error> {
-        let response: ContractDataResponse = client
-            .get("my/contracts", None::<&()>, reqwest::StatusCode::OK)
+    ) -> impl Stream<Item = Result<PublicAgentData, Error>> + '_ {
+        client.paginate(string!("agents"), None::<()>, MAX_PAGE_LIMIT)
+    }
+
+    pub async fn list_agents_page(
+        client: &SpaceTradersClient,
+        page: u32,
+        limit: u32,
+    ) -> Result<Page<PublicAgentData>, Error> {
+        client.get_page("agents", None::<&()>, page, limit).await
+    }
+
+    pub async fn get_public_data(
+        client: &SpaceTradersClient,
+        symbol: &str,
+    ) -> Result<PublicAgentData, Error> {
+        let response: PublicAgentDataResponse = client
+            .get(
+                &format!("agents/{}", symbol),
+                None::<&()>,
+                reqwest::StatusCode::OK,
+            )
+            .await?;
+
+        Ok(response.data)
+    }
+
+    pub async fn get_agent_events(
+        client: &SpaceTradersClient,
+    ) -> Result<Vec<AgentEventEntry>, Error> {
+        let response: AgentEventsResponse = client
+            .get("my/agent/events", None::<&()>, reqwest::StatusCode::OK)
             .await?;
 
         Ok(response.data)
     }
+
+    // Only returns events not returned by a previous call, oldest first.
+    pub async fn get_events(&mut self) -> Result<Vec<AgentEventEntry>, Error> {
+        let events = Agent::get_agent_events(&self.client).await?;
+
+        Ok(self.event_cursor.unseen(events))
+    }
+
+    pub async fn get_contracts_data(
+        client: &SpaceTradersClient,
+    ) -> Result<Vec<ContractData>, Error> {
+        client
+            .paginate(string!("my/contracts"), None::<()>, MAX_PAGE_LIMIT)
+            .try_collect()
+            .await
+    }
+}
+
+fn known<'a, T>(
+    items: &'a mut HashMap<String, T>,
+    key: &str,
+    endpoint: &str,
+) -> Result<&'a mut T, Error> {
+    items.get_mut(key).ok_or_else(|| Error::InvalidRequest {
+        endpoint: endpoint.to_string(),
+        message: format!("{} is not known to the agent", key),
+    })
 }
 
 #[cfg(test)]
 pub mod tests {
+    use futures::TryStreamExt;
     use mock_server::{MockServerBuilder, RequestMethod};
 
     use super::*;
 
     use crate::{
         account::tests::some_registration_response_data,
-        contract::tests::contract_data::some_contract_data, string,
+        contract::tests::contract_data::some_contract_data,
+        ship::tests::{some_other_ship, some_ship},
+        string,
     };
 
     fn some_token() -> Option<String> {
@@ -154,6 +351,63 @@ pub mod tests {
         assert_eq!(expected, actual);
     }
 
+    fn some_rival_agent_data() -> PublicAgentData {
+        PublicAgentData {
+            symbol: string!("FERRET"),
+            headquarters: string!("X1-KS52-A1"),
+            credits: 8012744,
+            starting_faction: Factions::Void,
+            ship_count: 14,
+        }
+    }
+
+    #[tokio::test]
+    async fn should_list_agents() {
+        let mock_server = MockServerBuilder::mock_once(
+            RequestMethod::Get,
+            "agents",
+            200,
+            None,
+            Some(&[("page", 1), ("limit", 20)]),
+        )
+        .await;
+
+        let client = SpaceTradersClient::with_url(&mock_server.url(), None);
+
+        let actual: Vec<PublicAgentData> = Agent::list_agents(&client).try_collect().await.unwrap();
+
+        let expected = vec![
+            PublicAgentData {
+                symbol: string!("BADGER"),
+                headquarters: string!("X1-RC42-A1"),
+                credits: 175000,
+                starting_faction: Factions::Cosmic,
+                ship_count: 2,
+            },
+            some_rival_agent_data(),
+        ];
+
+        assert_eq!(expected, actual);
+    }
+
+    #[tokio::test]
+    async fn should_get_public_data_for_an_agent() {
+        let mock_server = MockServerBuilder::mock_once(
+            RequestMethod::Get,
+            "agents/FERRET",
+            200,
+            None,
+            None::<&()>,
+        )
+        .await;
+
+        let client = SpaceTradersClient::with_url(&mock_server.url(), None);
+
+        let actual = Agent::get_public_data(&client, "FERRET").await.unwrap();
+
+        assert_eq!(some_rival_agent_data(), actual);
+    }
+
     #[tokio::test]
     async fn should_get_contract_data_with_just_a_client() {
         let mock_server = MockServerBuilder::mock_once(
@@ -161,7 +415,7 @@ pub mod tests {
             "my/contracts",
             200,
             some_token(),
-            None::<&()>,
+            Some(&[("page", 1), ("limit", 20)]),
         )
         .await;
 
@@ -194,7 +448,15 @@ pub mod tests {
                 "my/contracts",
                 200,
                 some_token(),
-                None::<&()>,
+                Some(&[("page", 1), ("limit", 20)]),
+            )
+            .await
+            .mock_response(
+                RequestMethod::Get,
+                "my/ships",
+                200,
+                some_token(),
+                Some(&[("page", 1), ("limit", 20)]),
             )
             .await
             .build();
@@ -213,9 +475,242 @@ pub mod tests {
                 some_contract_data().id,
                 Contract::new(client.clone(), some_contract_data()),
             )]),
+            ships: HashMap::from([
+                (
+                    string!("BADGER-1"),
+                    Ship::with_data(client.clone(), some_ship()),
+                ),
+                (
+                    string!("BADGER-2"),
+                    Ship::with_data(client.clone(), some_other_ship()),
+                ),
+            ]),
+            event_cursor: EventCursor::default(),
+        };
+
+        assert_eq!(expected, actual);
+    }
+
+    #[tokio::test]
+    async fn should_list_ships() {
+        let mock_server = MockServerBuilder::mock_once(
+            RequestMethod::Get,
+            "my/ships",
+            200,
+            some_token(),
+            Some(&[("page", 1), ("limit", 20)]),
+        )
+        .await;
+
+        let client = Arc::new(SpaceTradersClient::with_url(
+            &mock_server.url(),
+            some_token(),
+        ));
+
+        let actual = Agent::list_ships(&client).await.unwrap();
+
+        let expected = vec![
+            Ship::with_data(client.clone(), some_ship()),
+            Ship::with_data(client.clone(), some_other_ship()),
+        ];
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn agent_from_registration_data_should_own_the_starting_fleet() {
+        let mut agent = Agent::from_registration_data(
+            &SpaceTradersClient::new(Some("".to_string())),
+            some_registration_response_data(),
+        );
+
+        let mut symbols: Vec<&String> = agent.ships().map(|(symbol, _)| symbol).collect();
+        symbols.sort();
+
+        assert_eq!(vec!["BADGER-1", "BADGER-2"], symbols);
+        assert_eq!("BADGER-2", agent.edit_ship("BADGER-2").unwrap().symbol());
+        assert!(agent.edit_ship("BADGER-9").is_none());
+    }
+
+    #[tokio::test]
+    async fn should_purchase_ship_and_add_it_to_the_fleet() {
+        let mock_server = MockServerBuilder::mock_once(
+            RequestMethod::Post,
+            "my/ships",
+            201,
+            None,
+            Some(&serde_json::json!({
+                "shipType": "SHIP_PROBE",
+                "waypointSymbol": "X1-RC42-H53"
+            })),
+        )
+        .await;
+
+        let mut agent = Agent::from_registration_data(
+            &SpaceTradersClient::with_url(&mock_server.url(), None),
+            some_registration_response_data(),
+        );
+
+        let actual = agent
+            .purchase_ship(ShipType::Probe, "X1-RC42-H53")
+            .await
+            .unwrap();
+
+        let expected = ShipyardTransaction {
+            waypoint_symbol: string!("X1-RC42-H53"),
+            ship_symbol: Some(string!("BADGER-3")),
+            ship_type: ShipType::Probe,
+            price: 25000,
+            agent_symbol: string!("BADGER"),
+            timestamp: string!("2025-06-01T12:00:00.000Z"),
         };
 
         assert_eq!(expected, actual);
+        assert_eq!(150000, agent.data.credits);
+        assert_eq!(Some(3), agent.data.ship_count);
+        assert_eq!(3, agent.ships().count());
+        assert_eq!("BADGER-3", agent.edit_ship("BADGER-3").unwrap().symbol());
+    }
+
+    #[tokio::test]
+    async fn accepting_and_fulfilling_a_contract_should_update_credits() {
+        let id = some_contract_data().id;
+
+        let mock_server = MockServerBuilder::new()
+            .await
+            .mock_response::<serde_json::Value>(
+                RequestMethod::Post,
+                &format!("my/contracts/{}/accept", id),
+                200,
+                None,
+                None,
+            )
+            .await
+            .mock_response::<serde_json::Value>(
+                RequestMethod::Post,
+                &format!("my/contracts/{}/fulfill", id),
+                200,
+                None,
+                None,
+            )
+            .await
+            .build();
+
+        let mut agent = Agent::from_registration_data(
+            &SpaceTradersClient::with_url(&mock_server.url(), None),
+            some_registration_response_data(),
+        );
+
+        agent.accept_contract(&id).await.unwrap();
+        assert_eq!(176121, agent.data.credits);
+
+        agent.fulfill_contract(&id).await.unwrap();
+        assert_eq!(184397, agent.data.credits);
+        assert!(agent.edit_contract(&id).is_fulfilled());
+    }
+
+    #[tokio::test]
+    async fn contract_actions_with_unknown_ids_should_fail_without_a_request() {
+        let mut agent = Agent::from_registration_data(
+            &SpaceTradersClient::with_url("http://localhost:1", None),
+            some_registration_response_data(),
+        );
+        let id = some_contract_data().id;
+
+        let unknown_contract = agent.accept_contract("UNKNOWN").await.unwrap_err();
+        let unknown_ship = agent
+            .deliver_contract(&id, "UNKNOWN-1", TradeSymbol::IronOre, 10)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(
+            unknown_contract,
+            Error::InvalidRequest { ref endpoint, .. } if endpoint == "my/contracts/UNKNOWN/accept"
+        ));
+        assert!(matches!(unknown_ship, Error::InvalidRequest { .. }));
+        assert!(matches!(
+            agent.fulfill_contract("UNKNOWN").await,
+            Err(Error::InvalidRequest { .. })
+        ));
+        assert!(matches!(
+            agent.negotiate_contract("UNKNOWN-1").await,
+            Err(Error::InvalidRequest { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn negotiated_contract_should_be_tracked_by_the_agent() {
+        let mock_server = MockServerBuilder::mock_once::<serde_json::Value>(
+            RequestMethod::Post,
+            "my/ships/BADGER-1/negotiate/contract",
+            201,
+            None,
+            None,
+        )
+        .await;
+
+        let mut agent = Agent::from_registration_data(
+            &SpaceTradersClient::with_url(&mock_server.url(), None),
+            some_registration_response_data(),
+        );
+
+        let actual = agent.negotiate_contract("BADGER-1").await.unwrap();
+
+        assert_eq!("cmbh2k9xq01c2s60jd5f8r3wz", actual.data.id);
+        assert_eq!(2, agent.contracts().count());
+    }
+
+    #[tokio::test]
+    async fn should_get_agent_events() {
+        let mock_server = MockServerBuilder::mock_once(
+            RequestMethod::Get,
+            "my/agent/events",
+            200,
+            some_token(),
+            None::<&()>,
+        )
+        .await;
+
+        let client = SpaceTradersClient::with_url(&mock_server.url(), some_token());
+
+        let actual = Agent::get_agent_events(&client).await.unwrap();
+
+        assert_eq!(4, actual.len());
+        assert!(matches!(
+            actual[0].event,
+            AgentEvent::ShipCondition { ref ship_symbol, .. } if ship_symbol == "BADGER-1"
+        ));
+        assert!(matches!(
+            actual[1].event,
+            AgentEvent::Contract { ref contract, .. } if contract.accepted
+        ));
+        assert!(matches!(
+            actual[2].event,
+            AgentEvent::MarketTransaction(ref transaction) if transaction.total_price == 960
+        ));
+        assert!(matches!(actual[3].event, AgentEvent::Unknown { .. }));
+    }
+
+    #[tokio::test]
+    async fn polling_events_should_only_return_events_not_seen_before() {
+        let mock_server = MockServerBuilder::mock_once(
+            RequestMethod::Get,
+            "my/agent/events",
+            200,
+            None,
+            None::<&()>,
+        )
+        .await;
+
+        let client = SpaceTradersClient::with_url(&mock_server.url(), None);
+
+        let mut agent = Agent::from_registration_data(&client, some_registration_response_data());
+
+        let first = agent.get_events().await.unwrap();
+        let second = agent.get_events().await.unwrap();
+
+        assert_eq!(4, first.len());
+        assert!(second.is_empty());
     }
 
     #[test]
@@ -0,0 +1,303 @@
+use std::num::NonZeroU32;
+
+use crate::{
+    ship::TradeSymbol,
+    system::waypoint::{market::Market, WaypointData},
+};
+
+use super::ContractData;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SourceMarket {
+    pub symbol: String,
+    pub price_per_unit: i32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeliveryEstimate {
+    pub trade_symbol: TradeSymbol,
+    pub destination_symbol: String,
+    pub units: i32,
+    pub source: Option<SourceMarket>,
+    pub sourcing_cost: i64,
+    pub trips: i64,
+    pub distance: Option<f64>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContractEvaluation {
+    pub deliveries: Vec<DeliveryEstimate>,
+    pub payment: i64,
+    pub sourcing_cost: i64,
+    pub trips: i64,
+    pub distance: f64,
+    pub net_profit: i64,
+    pub unavailable: Vec<TradeSymbol>,
+}
+
+impl ContractEvaluation {
+    // Goods missing from every known market have no sourcing cost, so the profit is only an
+    // upper bound until they are found.
+    pub fn is_complete(&self) -> bool {
+        self.unavailable.is_empty()
+    }
+}
+
+pub fn evaluate(
+    contract: &ContractData,
+    markets: &[Market],
+    waypoints: &[WaypointData],
+    cargo_capacity: NonZeroU32,
+) -> ContractEvaluation {
+    let cargo_capacity = cargo_capacity.get() as i64;
+
+    let deliveries: Vec<DeliveryEstimate> = contract
+        .terms
+        .deliver
+        .iter()
+        .flatten()
+        .filter(|deliver| deliver.remaining_units() > 0)
+        .map(|deliver| {
+            let units = deliver.remaining_units();
+
+            let source = cheapest_source(markets, &deliver.trade_symbol);
+            let loads = units as i64;
+            let trips = loads / cargo_capacity + i64::from(loads % cargo_capacity > 0);
+
+            // Every trip but the last one comes back to the market for the next load.
+            let distance = source
+                .as_ref()
+                .and_then(|source| distance(waypoints, &source.symbol, &deliver.destination_symbol))
+                .map(|one_way| one_way * (2.0 * trips as f64 - 1.0));
+
+            DeliveryEstimate {
+                trade_symbol: deliver.trade_symbol.clone(),
+                destination_symbol: deliver.destination_symbol.clone(),
+                units,
+                sourcing_cost: source
+                    .as_ref()
+                    .map(|source| source.price_per_unit as i64 * units as i64)
+                    .unwrap_or(0),
+                source,
+                trips,
+                distance,
+            }
+        })
+        .collect();
+
+    // The payment on acceptance is already in the agent's credits once the contract is accepted.
+    let payment = if contract.accepted {
+        contract.terms.payment.on_fulfilled as i64
+    } else {
+        contract.terms.payment.on_accepted as i64 + contract.terms.payment.on_fulfilled as i64
+    };
+    let sourcing_cost = deliveries
+        .iter()
+        .map(|delivery| delivery.sourcing_cost)
+        .sum();
+
+    ContractEvaluation {
+        payment,
+        sourcing_cost,
+        trips: deliveries.iter().map(|delivery| delivery.trips).sum(),
+        distance: deliveries
+            .iter()
+            .filter_map(|delivery| delivery.distance)
+            .sum(),
+        net_profit: payment - sourcing_cost,
+        unavailable: deliveries
+            .iter()
+            .filter(|delivery| delivery.source.is_none())
+            .map(|delivery| delivery.trade_symbol.clone())
+            .collect(),
+        deliveries,
+    }
+}
+
+fn cheapest_source(markets: &[Market], trade_symbol: &TradeSymbol) -> Option<SourceMarket> {
+    markets
+        .iter()
+        .flat_map(|market| {
+            market
+                .trade_goods
+                .iter()
+                .flatten()
+                .filter(|good| good.symbol == *trade_symbol)
+                .map(|good| SourceMarket {
+                    symbol: market.symbol.clone(),
+                    price_per_unit: good.purchase_price,
+                })
+        })
+        .min_by_key(|source| source.price_per_unit)
+}
+
+fn distance(waypoints: &[WaypointData], from: &str, to: &str) -> Option<f64> {
+    let find = |symbol: &str| waypoints.iter().find(|waypoint| waypoint.symbol == symbol);
+    let (from, to) = (find(from)?, find(to)?);
+
+    Some(((to.x - from.x) as f64).hypot((to.y - from.y) as f64))
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use crate::{
+        contract::{
+            tests::contract_data::{some_accepted_contract_data, some_contract_data},
+            Deliver,
+        },
+        string,
+        system::waypoint::{
+            market::{ActivityLevel, MarketTradeGood, SupplyLevel, TradeType},
+            tests::some_planet,
+        },
+    };
+
+    fn waypoint(symbol: &str, x: i32, y: i32) -> WaypointData {
+        WaypointData {
+            symbol: string!(symbol),
+            x,
+            y,
+            ..some_planet()
+        }
+    }
+
+    fn market_selling(symbol: &str, trade_symbol: TradeSymbol, purchase_price: i32) -> Market {
+        Market {
+            symbol: string!(symbol),
+            exports: vec![],
+            imports: vec![],
+            exchange: vec![],
+            transactions: None,
+            trade_goods: Some(vec![MarketTradeGood {
+                symbol: trade_symbol,
+                trade_type: TradeType::Export,
+                trade_volume: 60,
+                supply: SupplyLevel::Moderate,
+                activity: ActivityLevel::Growing,
+                purchase_price,
+                sell_price: purchase_price - 10,
+            }]),
+        }
+    }
+
+    fn capacity(units: u32) -> NonZeroU32 {
+        NonZeroU32::new(units).unwrap()
+    }
+
+    fn waypoints() -> Vec<WaypointData> {
+        vec![
+            waypoint("X1-RC42-H52", 0, 0),
+            waypoint("X1-RC42-B7", 30, 40),
+            waypoint("X1-RC42-C3", 3, 4),
+        ]
+    }
+
+    #[test]
+    fn should_source_from_the_cheapest_market() {
+        let markets = vec![
+            market_selling("X1-RC42-C3", TradeSymbol::IronOre, 60),
+            market_selling("X1-RC42-B7", TradeSymbol::IronOre, 40),
+        ];
+
+        let actual = evaluate(
+            &some_accepted_contract_data(),
+            &markets,
+            &waypoints(),
+            capacity(40),
+        );
+
+        let expected = ContractEvaluation {
+            deliveries: vec![DeliveryEstimate {
+                trade_symbol: TradeSymbol::IronOre,
+                destination_symbol: string!("X1-RC42-H52"),
+                units: 62,
+                source: Some(SourceMarket {
+                    symbol: string!("X1-RC42-B7"),
+                    price_per_unit: 40,
+                }),
+                sourcing_cost: 2480,
+                trips: 2,
+                distance: Some(150.0),
+            }],
+            payment: 8276,
+            sourcing_cost: 2480,
+            trips: 2,
+            distance: 150.0,
+            net_profit: 5796,
+            unavailable: vec![],
+        };
+
+        assert_eq!(expected, actual);
+        assert!(actual.is_complete());
+    }
+
+    #[test]
+    fn should_only_plan_for_units_not_yet_delivered() {
+        let mut contract = some_accepted_contract_data();
+        contract.terms.deliver.as_mut().unwrap()[0].units_fulfilled = 30;
+        let markets = vec![market_selling("X1-RC42-C3", TradeSymbol::IronOre, 50)];
+
+        let actual = evaluate(&contract, &markets, &waypoints(), capacity(40));
+
+        assert_eq!(32, actual.deliveries[0].units);
+        assert_eq!(1, actual.trips);
+        assert_eq!(5.0, actual.distance);
+        assert_eq!(1600, actual.sourcing_cost);
+    }
+
+    #[test]
+    fn should_report_goods_not_sold_at_any_known_market() {
+        let mut contract = some_accepted_contract_data();
+        contract.terms.deliver.as_mut().unwrap().push(Deliver {
+            trade_symbol: TradeSymbol::CopperOre,
+            destination_symbol: string!("X1-RC42-H52"),
+            units_required: 10,
+            units_fulfilled: 0,
+        });
+        let markets = vec![market_selling("X1-RC42-C3", TradeSymbol::IronOre, 50)];
+
+        let actual = evaluate(&contract, &markets, &waypoints(), capacity(40));
+
+        assert_eq!(vec![TradeSymbol::CopperOre], actual.unavailable);
+        assert!(!actual.is_complete());
+        assert_eq!(None, actual.deliveries[1].distance);
+        assert_eq!(3100, actual.sourcing_cost);
+    }
+
+    #[test]
+    fn should_leave_distance_unknown_without_waypoint_coordinates() {
+        let markets = vec![market_selling("X1-RC42-C3", TradeSymbol::IronOre, 50)];
+
+        let actual = evaluate(&some_accepted_contract_data(), &markets, &[], capacity(40));
+
+        assert_eq!(None, actual.deliveries[0].distance);
+        assert_eq!(0.0, actual.distance);
+    }
+
+    #[test]
+    fn offered_contract_payment_should_include_the_payment_on_acceptance() {
+        let markets = vec![market_selling("X1-RC42-B7", TradeSymbol::IronOre, 40)];
+
+        let actual = evaluate(&some_contract_data(), &markets, &waypoints(), capacity(40));
+
+        assert_eq!(9966, actual.payment);
+        assert_eq!(7486, actual.net_profit);
+    }
+
+    #[test]
+    fn trips_and_distance_should_not_overflow_for_large_orders() {
+        let mut contract = some_accepted_contract_data();
+        contract.terms.deliver.as_mut().unwrap()[0].units_required = i32::MAX;
+        let markets = vec![market_selling("X1-RC42-C3", TradeSymbol::IronOre, 50)];
+
+        let actual = evaluate(&contract, &markets, &waypoints(), capacity(1));
+
+        assert_eq!(i32::MAX as i64, actual.trips);
+        assert_eq!(5.0 * (2.0 * i32::MAX as f64 - 1.0), actual.distance);
+
+        let actual = evaluate(&contract, &markets, &waypoints(), capacity(u32::MAX));
+
+        assert_eq!(1, actual.trips);
+    }
+}
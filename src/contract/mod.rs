@@ -3,10 +3,15 @@ use std::sync::Arc;
 use reqwest::StatusCode;
 use serde_derive::{Deserialize, Serialize};
 
+mod evaluation;
+pub use evaluation::*;
+
 use crate::{
     agent::AgentData,
     faction::Factions,
+    ship::{Cargo, Ship, TradeSymbol},
     space_traders_client::{Error, SpaceTradersClient},
+    timestamp::Timestamp,
 };
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -21,8 +26,8 @@ pub struct ContractData {
     pub accepted: bool,
     pub fulfilled: bool,
     #[serde(rename = "expiration")]
-    pub _deprecated: String, // date time? deprecated
-    pub deadline_to_accept: Option<String>, // date time?
+    pub _deprecated: Timestamp,
+    pub deadline_to_accept: Option<Timestamp>,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -31,12 +36,14 @@ pub enum ContractType {
     Procurement,
     Transport,
     Shuttle,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Terms {
-    pub deadline: String, // date time?
+    pub deadline: Timestamp,
     pub payment: Payment,
     pub deliver: Option<Vec<Deliver>>,
 }
@@ -51,12 +58,18 @@ pub struct Payment {
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Deliver {
-    pub trade_symbol: String,
+    pub trade_symbol: TradeSymbol,
     pub destination_symbol: String,
     pub units_required: i32,
     pub units_fulfilled: i32,
 }
 
+impl Deliver {
+    pub fn remaining_units(&self) -> i32 {
+        (self.units_required - self.units_fulfilled).max(0)
+    }
+}
+
 #[derive(Debug, PartialEq, Deserialize)]
 pub struct ContractAcceptResponseData {
     pub contract: ContractData,
@@ -68,6 +81,30 @@ pub struct ContractAcceptResponse {
     pub data: ContractAcceptResponseData,
 }
 
+#[derive(Debug, PartialEq, Deserialize)]
+struct ContractResponse {
+    data: ContractData,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct DeliverRequest<'a> {
+    ship_symbol: &'a str,
+    trade_symbol: TradeSymbol,
+    units: i32,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct DeliverResponseData {
+    contract: ContractData,
+    cargo: Cargo,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct DeliverResponse {
+    data: DeliverResponseData,
+}
+
 #[derive(Debug, PartialEq)]
 pub struct Contract {
     client: Arc<SpaceTradersClient>,
@@ -83,7 +120,64 @@ impl Contract {
         self.data.accepted
     }
 
-    pub async fn accept(&mut self) -> Result<(), Error> {
+    pub fn is_fulfilled(&self) -> bool {
+        self.data.fulfilled
+    }
+
+    // An offered contract has to be accepted by `deadline_to_accept`, once accepted it has to be
+    // fulfilled by the terms deadline.
+    pub fn deadline(&self) -> &Timestamp {
+        match &self.data.deadline_to_accept {
+            Some(deadline_to_accept) if !self.data.accepted => deadline_to_accept,
+            _ => &self.data.terms.deadline,
+        }
+    }
+
+    // A deadline the server sent in an unexpected format is never considered passed.
+    #[cfg(feature = "chrono")]
+    pub fn is_expired(&self, now: chrono::DateTime<chrono::Utc>) -> bool {
+        !self.data.fulfilled
+            && self
+                .deadline()
+                .to_date_time()
+                .is_some_and(|deadline| now >= deadline)
+    }
+
+    #[cfg(feature = "chrono")]
+    pub fn time_remaining(&self, now: chrono::DateTime<chrono::Utc>) -> Option<chrono::TimeDelta> {
+        let deadline = self.deadline().to_date_time()?;
+
+        Some((deadline - now).max(chrono::TimeDelta::zero()))
+    }
+
+    pub fn remaining_units(&self) -> i32 {
+        self.data
+            .terms
+            .deliver
+            .iter()
+            .flatten()
+            .map(Deliver::remaining_units)
+            .sum()
+    }
+
+    pub async fn get_contract_data(
+        client: &SpaceTradersClient,
+        id: &str,
+    ) -> Result<ContractData, Error> {
+        let response: ContractResponse = client
+            .get(&format!("my/contracts/{}", id), None::<&()>, StatusCode::OK)
+            .await?;
+
+        Ok(response.data)
+    }
+
+    pub async fn refresh(&mut self) -> Result<(), Error> {
+        self.data = Contract::get_contract_data(&self.client, &self.data.id).await?;
+
+        Ok(())
+    }
+
+    pub async fn accept(&mut self) -> Result<AgentData, Error> {
         let response: ContractAcceptResponse = self
             .client
             .post(
@@ -94,8 +188,49 @@ impl Contract {
 
         self.data = response.data.contract;
 
+        Ok(response.data.agent)
+    }
+
+    pub async fn deliver(
+        &mut self,
+        ship: &mut Ship,
+        trade_symbol: TradeSymbol,
+        units: i32,
+    ) -> Result<(), Error> {
+        let request = DeliverRequest {
+            ship_symbol: ship.symbol(),
+            trade_symbol,
+            units,
+        };
+
+        let response: DeliverResponse = self
+            .client
+            .post_with_body(
+                &format!("my/contracts/{}/deliver", &self.data.id),
+                &request,
+                StatusCode::OK,
+            )
+            .await?;
+
+        self.data = response.data.contract;
+        ship.update_cargo(response.data.cargo);
+
         Ok(())
     }
+
+    pub async fn fulfill(&mut self) -> Result<AgentData, Error> {
+        let response: ContractAcceptResponse = self
+            .client
+            .post(
+                &format!("my/contracts/{}/fulfill", &self.data.id),
+                StatusCode::OK,
+            )
+            .await?;
+
+        self.data = response.data.contract;
+
+        Ok(response.data.agent)
+    }
 }
 
 #[cfg(test)]
@@ -111,16 +246,16 @@ pub mod tests {
                 contract_type: ContractType::Procurement,
                 accepted: false,
                 fulfilled: false,
-                _deprecated: string!("2025-05-30T22:47:42.900Z"),
-                deadline_to_accept: Some(string!("2025-05-30T22:47:42.900Z")),
+                _deprecated: "2025-05-30T22:47:42.900Z".parse().unwrap(),
+                deadline_to_accept: Some("2025-05-30T22:47:42.900Z".parse().unwrap()),
                 terms: Terms {
-                    deadline: string!("2025-06-05T22:47:42.900Z"),
+                    deadline: "2025-06-05T22:47:42.900Z".parse().unwrap(),
                     payment: Payment {
                         on_accepted: 1690,
                         on_fulfilled: 8276,
                     },
                     deliver: Some(vec![Deliver {
-                        trade_symbol: string!("IRON_ORE"),
+                        trade_symbol: TradeSymbol::IronOre,
                         destination_symbol: string!("X1-RC42-H52"),
                         units_required: 62,
                         units_fulfilled: 0,
@@ -170,6 +305,16 @@ pub mod tests {
 
             assert_eq!(expected, actual);
         }
+
+        #[test]
+        fn unknown_contract_type_should_round_trip() {
+            let json_str = r#""SALVAGE""#;
+
+            let actual: ContractType = serde_json::from_str(json_str).unwrap();
+
+            assert_eq!(ContractType::Unknown(string!("SALVAGE")), actual);
+            assert_eq!(json_str, serde_json::to_string(&actual).unwrap());
+        }
     }
 
     pub mod contract {
@@ -180,10 +325,12 @@ pub mod tests {
         use crate::{
             contract::{
                 tests::contract_data::{some_accepted_contract_data, some_contract_data},
-                Contract,
+                Contract, ContractData,
             },
+            ship::{tests::some_ship, Cargo, InventoryItem, Ship, ShipData, TradeSymbol},
             space_traders_client::SpaceTradersClient,
             string,
+            timestamp::Timestamp,
         };
 
         fn some_token() -> Option<String> {
@@ -217,9 +364,173 @@ pub mod tests {
             let mut contract = Contract::new(Arc::new(space_traders_client), data);
             assert!(!contract.is_accepted());
 
-            contract.accept().await.unwrap();
+            let agent_data = contract.accept().await.unwrap();
             assert_eq!(contract.data, some_accepted_contract_data());
             assert!(contract.is_accepted());
+            assert_eq!(176121, agent_data.credits);
+        }
+
+        #[tokio::test]
+        async fn should_refresh_contract() {
+            let data = some_contract_data();
+
+            let mock_server = MockServerBuilder::mock_once::<serde_json::Value>(
+                RequestMethod::Get,
+                &format!("my/contracts/{}", &data.id),
+                200,
+                some_token(),
+                None,
+            )
+            .await;
+
+            let client = SpaceTradersClient::with_url(&mock_server.url(), some_token());
+            let mut contract = Contract::new(Arc::new(client), data);
+
+            contract.refresh().await.unwrap();
+
+            assert_eq!(some_accepted_contract_data(), contract.data);
+        }
+
+        #[tokio::test]
+        async fn delivering_should_update_contract_progress_and_ship_cargo() {
+            let data = some_accepted_contract_data();
+
+            let mock_server = MockServerBuilder::mock_once(
+                RequestMethod::Post,
+                &format!("my/contracts/{}/deliver", &data.id),
+                200,
+                some_token(),
+                Some(&serde_json::json!({
+                    "shipSymbol": "BADGER-1",
+                    "tradeSymbol": "IRON_ORE",
+                    "units": 20
+                })),
+            )
+            .await;
+
+            let client = Arc::new(SpaceTradersClient::with_url(
+                &mock_server.url(),
+                some_token(),
+            ));
+            let mut contract = Contract::new(client.clone(), data);
+            let mut ship = Ship::with_data(
+                client,
+                ShipData {
+                    cargo: Cargo {
+                        capacity: 40,
+                        units: 20,
+                        inventory: vec![InventoryItem {
+                            symbol: TradeSymbol::IronOre,
+                            name: string!("Iron Ore"),
+                            description: string!("Iron ore."),
+                            units: 20,
+                        }],
+                    },
+                    ..some_ship()
+                },
+            );
+
+            contract
+                .deliver(&mut ship, TradeSymbol::IronOre, 20)
+                .await
+                .unwrap();
+
+            let deliver = &contract.data.terms.deliver.as_ref().unwrap()[0];
+            assert_eq!(20, deliver.units_fulfilled);
+            assert_eq!(0, ship.get_data().await.unwrap().cargo.units);
+        }
+
+        #[tokio::test]
+        async fn should_fulfill_contract() {
+            let data = some_accepted_contract_data();
+
+            let mock_server = MockServerBuilder::mock_once::<serde_json::Value>(
+                RequestMethod::Post,
+                &format!("my/contracts/{}/fulfill", &data.id),
+                200,
+                some_token(),
+                None,
+            )
+            .await;
+
+            let client = SpaceTradersClient::with_url(&mock_server.url(), some_token());
+            let mut contract = Contract::new(Arc::new(client), data);
+
+            let agent_data = contract.fulfill().await.unwrap();
+
+            assert!(contract.is_fulfilled());
+            assert_eq!(184397, agent_data.credits);
+        }
+
+        fn partially_delivered_contract() -> Contract {
+            let mut data = some_accepted_contract_data();
+            data.terms.deliver.as_mut().unwrap()[0].units_fulfilled = 20;
+
+            Contract::new(Arc::new(SpaceTradersClient::new(None)), data)
+        }
+
+        #[test]
+        fn should_count_units_left_to_deliver() {
+            assert_eq!(42, partially_delivered_contract().remaining_units());
+        }
+
+        #[test]
+        fn deadline_should_depend_on_whether_the_contract_was_accepted() {
+            let offered = Contract::new(
+                Arc::new(SpaceTradersClient::new(None)),
+                some_contract_data(),
+            );
+
+            assert_eq!(
+                "2025-05-30T22:47:42.900Z".parse::<Timestamp>().unwrap(),
+                *offered.deadline()
+            );
+            assert_eq!(
+                "2025-06-05T22:47:42.900Z".parse::<Timestamp>().unwrap(),
+                *partially_delivered_contract().deadline()
+            );
+        }
+
+        #[cfg(feature = "chrono")]
+        #[test]
+        fn should_expire_at_the_deadline() {
+            let contract = partially_delivered_contract();
+            let now = |date: &str| date.parse::<chrono::DateTime<chrono::Utc>>().unwrap();
+
+            assert!(!contract.is_expired(now("2025-06-05T22:47:42.899Z")));
+            assert!(contract.is_expired(now("2025-06-05T22:47:42.900Z")));
+            assert_eq!(
+                Some(chrono::TimeDelta::seconds(60)),
+                contract.time_remaining(now("2025-06-05T22:46:42.900Z"))
+            );
+            assert_eq!(
+                Some(chrono::TimeDelta::zero()),
+                contract.time_remaining(now("2025-06-06T00:00:00.000Z"))
+            );
+        }
+
+        #[test]
+        fn dates_should_round_trip() {
+            let data = some_contract_data();
+
+            let json = serde_json::to_value(&data).unwrap();
+
+            assert_eq!("2025-06-05T22:47:42.900Z", json["terms"]["deadline"]);
+            assert_eq!(data, serde_json::from_value(json).unwrap());
+        }
+
+        #[test]
+        fn fulfilled_contract_should_be_reported_as_fulfilled() {
+            let contract = Contract::new(
+                Arc::new(SpaceTradersClient::new(None)),
+                ContractData {
+                    fulfilled: true,
+                    ..some_accepted_contract_data()
+                },
+            );
+
+            assert!(contract.is_accepted());
+            assert!(contract.is_fulfilled());
         }
     }
 }
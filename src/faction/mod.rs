@@ -41,6 +41,8 @@ pub enum Factions {
     Ancients,
     Shadow,
     Ethereal,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 #[cfg(test)]
@@ -118,4 +120,14 @@ pub mod tests {
 
         assert_eq!(expected, actual);
     }
+
+    #[test]
+    fn unknown_factions_should_round_trip() {
+        let json_str = r#""NOMAD""#;
+
+        let actual: Factions = serde_json::from_str(json_str).unwrap();
+
+        assert_eq!(Factions::Unknown(string!("NOMAD")), actual);
+        assert_eq!(json_str, serde_json::to_string(&actual).unwrap());
+    }
 }
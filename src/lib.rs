@@ -4,6 +4,8 @@ pub mod contract;
 pub mod faction;
 pub mod ship;
 pub mod space_traders_client;
+pub mod status;
 pub mod system;
+pub mod timestamp;
 
 mod test_utils;
@@ -165,6 +165,8 @@ pub enum TradeSymbol {
     ShipRefiningFreighter,
     ShipSurveyor,
     ShipBulkFreighter,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 #[cfg(test)]
@@ -254,4 +256,14 @@ pub mod tests {
 
         assert_eq!(expected, actual);
     }
+
+    #[test]
+    fn unknown_trade_symbol_should_round_trip() {
+        let json_str = r#""DARK_MATTER""#;
+
+        let actual: TradeSymbol = serde_json::from_str(json_str).unwrap();
+
+        assert_eq!(TradeSymbol::Unknown(string!("DARK_MATTER")), actual);
+        assert_eq!(json_str, serde_json::to_string(&actual).unwrap());
+    }
 }
@@ -1,4 +1,4 @@
-use serde_derive::Deserialize;
+use serde_derive::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, PartialEq, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -11,16 +11,19 @@ pub struct Crew {
     pub wages: i32,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "UPPERCASE")]
 pub enum RotationMode {
     Strict,
     Relaxed,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 #[cfg(test)]
 pub mod tests {
     use super::*;
+    use crate::string;
 
     pub fn some_crew() -> Crew {
         Crew {
@@ -79,4 +82,14 @@ pub mod tests {
 
         assert_eq!(expected, actual);
     }
+
+    #[test]
+    fn unknown_rotation_mode_should_round_trip() {
+        let json_str = r#""LOOSE""#;
+
+        let actual: RotationMode = serde_json::from_str(json_str).unwrap();
+
+        assert_eq!(RotationMode::Unknown(string!("LOOSE")), actual);
+        assert_eq!(json_str, serde_json::to_string(&actual).unwrap());
+    }
 }
@@ -1,4 +1,4 @@
-use serde_derive::Deserialize;
+use serde_derive::{Deserialize, Serialize};
 
 use super::Requirements;
 
@@ -13,7 +13,7 @@ pub struct Engine {
     pub requirements: Requirements,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum EngineType {
     #[serde(rename = "ENGINE_IMPULSE_DRIVE_I")]
     ImpulseDriveI,
@@ -23,6 +23,8 @@ pub enum EngineType {
     IonDriveII,
     #[serde(rename = "ENGINE_HYPER_DRIVE_I")]
     HyperDriveI,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 #[cfg(test)]
@@ -105,4 +107,14 @@ pub mod tests {
 
         assert_eq!(expected, actual);
     }
+
+    #[test]
+    fn unknown_engine_type_should_round_trip() {
+        let json_str = r#""ENGINE_WARP_DRIVE_I""#;
+
+        let actual: EngineType = serde_json::from_str(json_str).unwrap();
+
+        assert_eq!(EngineType::Unknown(string!("ENGINE_WARP_DRIVE_I")), actual);
+        assert_eq!(json_str, serde_json::to_string(&actual).unwrap());
+    }
 }
@@ -1,4 +1,4 @@
-use serde_derive::Deserialize;
+use serde_derive::{Deserialize, Serialize};
 
 use super::Requirements;
 
@@ -15,7 +15,7 @@ pub struct Frame {
     pub requirements: Requirements,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum FrameType {
     #[serde(rename = "FRAME_PROBE")]
     Probe,
@@ -47,6 +47,8 @@ pub enum FrameType {
     Cruiser,
     #[serde(rename = "FRAME_CARRIER")]
     Carrier,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 #[cfg(test)]
@@ -137,4 +139,14 @@ pub mod tests {
 
         assert_eq!(expected, actual);
     }
+
+    #[test]
+    fn unknown_frame_type_should_round_trip() {
+        let json_str = r#""FRAME_BULK_FREIGHTER""#;
+
+        let actual: FrameType = serde_json::from_str(json_str).unwrap();
+
+        assert_eq!(FrameType::Unknown(string!("FRAME_BULK_FREIGHTER")), actual);
+        assert_eq!(json_str, serde_json::to_string(&actual).unwrap());
+    }
 }
@@ -1,4 +1,6 @@
-use std::sync::Arc;
+use std::{collections::VecDeque, sync::Arc};
+
+use derivative::Derivative;
 
 use serde_derive::{Deserialize, Serialize};
 
@@ -55,9 +57,20 @@ pub use mount::*;
 mod fuel;
 pub use fuel::*;
 
-use crate::space_traders_client::{Error, SpaceTradersClient};
+mod survey;
+pub use survey::*;
 
-#[derive(Debug, PartialEq, Deserialize)]
+mod trade;
+pub use trade::*;
+
+use crate::{
+    contract::{Contract, ContractData},
+    space_traders_client::{Error, ErrorCode, SpaceTradersClient},
+};
+
+const CONDITION_EVENT_HISTORY: usize = 50;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum ShipConditionEventSymbol {
     ReactorOverload,
@@ -87,17 +100,21 @@ pub enum ShipConditionEventSymbol {
     PressureDifferentialStress,
     ElectromagneticSurgeEffects,
     AtmosphericEntryHeat,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
-#[derive(Debug, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum ShipComponent {
     Frame,
     Reactor,
     Engine,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
-#[derive(Debug, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct ShipConditionEvent {
     pub symbol: ShipConditionEventSymbol,
     pub component: ShipComponent,
@@ -124,7 +141,8 @@ struct NavChangeResponse {
 struct NavigateData {
     nav: Nav,
     fuel: Fuel,
-    // events: Vec<ShipConditionEvent>, // TODO: something with this
+    #[serde(default)]
+    events: Vec<ShipConditionEvent>,
 }
 
 #[derive(Debug, PartialEq, Deserialize)]
@@ -132,6 +150,18 @@ struct NavigateResponse {
     data: NavigateData,
 }
 
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct NavigateRequest<'a> {
+    waypoint_symbol: &'a str,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct FlightModeRequest {
+    flight_mode: FlightMode,
+}
+
 #[derive(Debug, PartialEq, Deserialize)]
 struct ExtractionYield {
     units: u32,
@@ -150,7 +180,8 @@ struct ExtractionData {
     extraction: Extraction,
     cooldown: Cooldown,
     cargo: Cargo,
-    // events: Vec<ShipConditionEvent>, // TODO: something with this
+    #[serde(default)]
+    events: Vec<ShipConditionEvent>,
     // modifiers: Vec<WaypointModifier>, // TODO: something with this
 }
 
@@ -159,6 +190,75 @@ struct ExtractionResponse {
     data: ExtractionData,
 }
 
+#[derive(Debug, PartialEq, Deserialize)]
+pub struct SiphonYield {
+    pub symbol: TradeSymbol,
+    pub units: u32,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Siphon {
+    pub ship_symbol: String,
+    pub r#yield: SiphonYield,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct SiphonData {
+    siphon: Siphon,
+    cooldown: Cooldown,
+    cargo: Cargo,
+    #[serde(default)]
+    events: Vec<ShipConditionEvent>,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct SiphonResponse {
+    data: SiphonData,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RefinementYield {
+    pub trade_symbol: TradeSymbol,
+    pub units: i32,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+pub struct Refinement {
+    pub produced: Vec<RefinementYield>,
+    pub consumed: Vec<RefinementYield>,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct RefineData {
+    #[serde(flatten)]
+    refinement: Refinement,
+    cooldown: Cooldown,
+    cargo: Cargo,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct RefineResponse {
+    data: RefineData,
+}
+
+#[derive(Serialize)]
+struct RefineRequest {
+    produce: TradeSymbol,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct SurveyData {
+    cooldown: Cooldown,
+    surveys: Vec<Survey>,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct SurveyResponse {
+    data: SurveyData,
+}
+
 #[derive(Debug, PartialEq, Deserialize)]
 struct JettisonData {
     cargo: Cargo,
@@ -175,11 +275,24 @@ struct JettisonRequest {
     pub units: i32,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Deserialize)]
+struct NegotiateContractData {
+    contract: ContractData,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct NegotiateContractResponse {
+    data: NegotiateContractData,
+}
+
+#[derive(Derivative)]
+#[derivative(Debug, PartialEq)]
 pub struct Ship {
     symbol: String,
     client: Arc<SpaceTradersClient>,
     data: Option<ShipData>,
+    #[derivative(PartialEq = "ignore")]
+    condition_events: VecDeque<ShipConditionEvent>,
 }
 
 impl Ship {
@@ -202,7 +315,8 @@ impl Ship {
         Ok(Ship {
             data: Some(Ship::get_ship_data(&client, symbol).await?),
             symbol: symbol.to_string(),
-            client: client,
+            client,
+            condition_events: VecDeque::new(),
         })
     }
 }
@@ -213,6 +327,7 @@ impl Ship {
             client: client.clone(),
             symbol: symbol.to_string(),
             data: None,
+            condition_events: VecDeque::new(),
         }
     }
 
@@ -221,9 +336,29 @@ impl Ship {
             client: client.clone(),
             symbol: data.symbol.to_string(),
             data: Some(data),
+            condition_events: VecDeque::new(),
         }
     }
 
+    pub fn symbol(&self) -> &str {
+        &self.symbol
+    }
+
+    // Oldest first, only the most recent events are kept.
+    pub fn condition_events(&self) -> &VecDeque<ShipConditionEvent> {
+        &self.condition_events
+    }
+
+    fn record_condition_events(&mut self, events: &[ShipConditionEvent]) {
+        self.condition_events.extend(events.iter().cloned());
+
+        let overflow = self
+            .condition_events
+            .len()
+            .saturating_sub(CONDITION_EVENT_HISTORY);
+        self.condition_events.drain(..overflow);
+    }
+
     pub async fn get_data(&mut self) -> Result<ShipData, Error> {
         let data = match &self.data {
             Some(cached) => cached.clone(),
@@ -265,23 +400,47 @@ impl Ship {
         Ok(())
     }
 
-    pub async fn navigate_to(&mut self, waypoint_symbol: &str) -> Result<(), Error> {
+    pub async fn navigate_to(
+        &mut self,
+        waypoint_symbol: &str,
+    ) -> Result<Vec<ShipConditionEvent>, Error> {
         let response: NavigateResponse = self
             .client
             .post_with_body(
                 &format!("my/ships/{}/navigate", self.symbol),
-                &("waypointSymbol", waypoint_symbol),
+                &NavigateRequest { waypoint_symbol },
                 reqwest::StatusCode::OK,
             )
             .await?;
 
-        self.data.as_mut().unwrap().nav = response.data.nav;
-        self.data.as_mut().unwrap().fuel = response.data.fuel;
+        self.update_nav(response.data.nav);
+        self.update_fuel(response.data.fuel);
+        self.record_condition_events(&response.data.events);
 
-        Ok(())
+        Ok(response.data.events)
     }
 
-    pub async fn extract(&mut self) -> Result<Extraction, Error> {
+    pub async fn set_flight_mode(
+        &mut self,
+        flight_mode: FlightMode,
+    ) -> Result<Vec<ShipConditionEvent>, Error> {
+        let response: NavigateResponse = self
+            .client
+            .patch_with_body(
+                &format!("my/ships/{}/nav", self.symbol),
+                &FlightModeRequest { flight_mode },
+                reqwest::StatusCode::OK,
+            )
+            .await?;
+
+        self.update_nav(response.data.nav);
+        self.update_fuel(response.data.fuel);
+        self.record_condition_events(&response.data.events);
+
+        Ok(response.data.events)
+    }
+
+    pub async fn extract(&mut self) -> Result<(Extraction, Vec<ShipConditionEvent>), Error> {
         let response: ExtractionResponse = self
             .client
             .post(
@@ -290,10 +449,101 @@ impl Ship {
             )
             .await?;
 
-        self.data.as_mut().unwrap().cooldown = response.data.cooldown;
-        self.data.as_mut().unwrap().cargo = response.data.cargo;
+        self.update_cooldown(response.data.cooldown);
+        self.update_cargo(response.data.cargo);
+        self.record_condition_events(&response.data.events);
 
-        Ok(response.data.extraction)
+        Ok((response.data.extraction, response.data.events))
+    }
+
+    pub async fn extract_with_survey(
+        &mut self,
+        survey: &Survey,
+    ) -> Result<(Extraction, Vec<ShipConditionEvent>), Error> {
+        let response: ExtractionResponse = self
+            .client
+            .post_with_body(
+                &format!("my/ships/{}/extract/survey", self.symbol),
+                survey,
+                reqwest::StatusCode::CREATED,
+            )
+            .await?;
+
+        self.update_cooldown(response.data.cooldown);
+        self.update_cargo(response.data.cargo);
+        self.record_condition_events(&response.data.events);
+
+        Ok((response.data.extraction, response.data.events))
+    }
+
+    pub async fn siphon(&mut self) -> Result<(Siphon, Vec<ShipConditionEvent>), Error> {
+        let data = self.get_data().await?;
+
+        if !data.mounts.iter().any(|mount| mount.symbol.is_gas_siphon()) {
+            return Err(self.missing(ErrorCode::ShipMissingGasSiphonsError, "gas siphon mount"));
+        }
+
+        let response: SiphonResponse = self
+            .client
+            .post(
+                &format!("my/ships/{}/siphon", self.symbol),
+                reqwest::StatusCode::CREATED,
+            )
+            .await?;
+
+        self.update_cooldown(response.data.cooldown);
+        self.update_cargo(response.data.cargo);
+        self.record_condition_events(&response.data.events);
+
+        Ok((response.data.siphon, response.data.events))
+    }
+
+    pub async fn refine(&mut self, produce: TradeSymbol) -> Result<Refinement, Error> {
+        let data = self.get_data().await?;
+
+        if !data
+            .modules
+            .iter()
+            .any(|module| module.symbol.is_gas_processor() || module.symbol.is_refinery())
+        {
+            return Err(self.missing(ErrorCode::ShipMissingRefineryError, "refinery module"));
+        }
+
+        let response: RefineResponse = self
+            .client
+            .post_with_body(
+                &format!("my/ships/{}/refine", self.symbol),
+                &RefineRequest { produce },
+                reqwest::StatusCode::CREATED,
+            )
+            .await?;
+
+        self.update_cooldown(response.data.cooldown);
+        self.update_cargo(response.data.cargo);
+
+        Ok(response.data.refinement)
+    }
+
+    // Mirrors the error the API would return so callers can handle both the same way.
+    fn missing(&self, code: ErrorCode, equipment: &str) -> Error {
+        Error::Precondition {
+            code,
+            message: format!("Ship {} is missing a {}.", self.symbol, equipment),
+        }
+    }
+
+    pub async fn survey(&mut self) -> Result<Vec<Survey>, Error> {
+        let response: SurveyResponse = self
+            .client
+            .post(
+                &format!("my/ships/{}/survey", self.symbol),
+                reqwest::StatusCode::CREATED,
+            )
+            .await?;
+
+        self.update_cooldown(response.data.cooldown);
+
+        Ok(response.data.surveys)
     }
 
     pub async fn jettison(&mut self, symbol: TradeSymbol, units: i32) -> Result<(), Error> {
@@ -312,6 +562,42 @@ impl Ship {
 
         Ok(())
     }
+
+    pub async fn negotiate_contract(&mut self) -> Result<Contract, Error> {
+        let response: NegotiateContractResponse = self
+            .client
+            .post(
+                &format!("my/ships/{}/negotiate/contract", self.symbol),
+                reqwest::StatusCode::CREATED,
+            )
+            .await?;
+
+        Ok(Contract::new(self.client.clone(), response.data.contract))
+    }
+
+    pub(crate) fn update_cargo(&mut self, cargo: Cargo) {
+        if let Some(data) = self.data.as_mut() {
+            data.cargo = cargo;
+        }
+    }
+
+    fn update_nav(&mut self, nav: Nav) {
+        if let Some(data) = self.data.as_mut() {
+            data.nav = nav;
+        }
+    }
+
+    pub(crate) fn update_fuel(&mut self, fuel: Fuel) {
+        if let Some(data) = self.data.as_mut() {
+            data.fuel = fuel;
+        }
+    }
+
+    fn update_cooldown(&mut self, cooldown: Cooldown) {
+        if let Some(data) = self.data.as_mut() {
+            data.cooldown = cooldown;
+        }
+    }
 }
 
 #[cfg(test)]
@@ -329,6 +615,7 @@ pub mod tests {
     use super::mount::tests::*;
     use super::nav::tests::nav::*;
     use super::reactor::tests::*;
+    use super::survey::tests::*;
     use super::*;
     use crate::faction::Factions;
     use crate::ship::registration::tests::*;
@@ -627,7 +914,7 @@ pub mod tests {
             "my/ships/SNAKE-1/navigate",
             200,
             None,
-            Some(&("waypointSymbol", "X1-CB91-AA5Z")),
+            Some(&json!({"waypointSymbol": "X1-CB91-AA5Z"})),
         )
         .await;
 
@@ -635,7 +922,16 @@ pub mod tests {
 
         let mut ship = Ship::with_data(client.clone(), snake_ship_in_orbit());
 
-        let _ = ship.navigate_to("X1-CB91-AA5Z").await;
+        let events = ship.navigate_to("X1-CB91-AA5Z").await.unwrap();
+
+        assert_eq!(
+            vec![ShipConditionEventSymbol::CoolantSystemAgeing],
+            events
+                .iter()
+                .map(|event| event.symbol.clone())
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(events, Vec::from(ship.condition_events().clone()));
 
         let actual = ship;
 
@@ -644,6 +940,68 @@ pub mod tests {
         assert_eq!(expected, actual);
     }
 
+    #[tokio::test]
+    async fn should_set_flight_mode() {
+        let mock_server = MockServerBuilder::mock_once(
+            RequestMethod::Patch,
+            "my/ships/SNAKE-1/nav",
+            200,
+            None,
+            Some(&json!({"flightMode": "DRIFT"})),
+        )
+        .await;
+
+        let client = Arc::new(SpaceTradersClient::with_url(&mock_server.url(), None));
+
+        let mut ship = Ship::with_data(client.clone(), snake_ship_in_orbit());
+
+        ship.set_flight_mode(FlightMode::Drift).await.unwrap();
+
+        let mut expected = snake_ship_in_orbit();
+        expected.nav.flight_mode = FlightMode::Drift;
+
+        assert_eq!(Ship::with_data(client, expected), ship);
+    }
+
+    #[tokio::test]
+    async fn moving_without_cached_data_should_not_panic() {
+        let mock_server = MockServerBuilder::new()
+            .await
+            .mock_response(
+                RequestMethod::Patch,
+                "my/ships/SNAKE-1/nav",
+                200,
+                None,
+                Some(&json!({"flightMode": "DRIFT"})),
+            )
+            .await
+            .mock_response(
+                RequestMethod::Post,
+                "my/ships/SNAKE-1/navigate",
+                200,
+                None,
+                Some(&json!({"waypointSymbol": "X1-CB91-AA5Z"})),
+            )
+            .await
+            .mock_response(
+                RequestMethod::Post,
+                "my/ships/SNAKE-1/extract",
+                201,
+                None,
+                None::<&()>,
+            )
+            .await
+            .build();
+
+        let client = Arc::new(SpaceTradersClient::with_url(&mock_server.url(), None));
+
+        let mut ship = Ship::new(client, "SNAKE-1");
+
+        assert!(ship.set_flight_mode(FlightMode::Drift).await.is_ok());
+        assert!(ship.navigate_to("X1-CB91-AA5Z").await.is_ok());
+        assert!(ship.extract().await.is_ok());
+    }
+
     #[tokio::test]
     async fn should_extract_resources() {
         let mock_server = MockServerBuilder::mock_once(
@@ -659,7 +1017,13 @@ pub mod tests {
 
         let mut ship = Ship::with_data(client.clone(), snake_ship());
 
-        let expected_extraction = ship.extract().await.unwrap();
+        let (expected_extraction, events) = ship.extract().await.unwrap();
+
+        assert_eq!(
+            ShipConditionEventSymbol::SolarFlareInterference,
+            events[0].symbol
+        );
+        assert_eq!(1, ship.condition_events().len());
 
         let actual_ship = ship;
 
@@ -671,6 +1035,272 @@ pub mod tests {
         assert_eq!(expected_extraction, actual_extraction)
     }
 
+    #[tokio::test]
+    async fn should_extract_resources_with_survey() {
+        let mock_server = MockServerBuilder::mock_once(
+            RequestMethod::Post,
+            "my/ships/SNAKE-1/extract/survey",
+            201,
+            None,
+            Some(&json!({
+                "signature": "X1-CB91-A1-4F2A1C",
+                "symbol": "X1-CB91-A1",
+                "deposits": [
+                    {"symbol": "ICE_WATER"},
+                    {"symbol": "ICE_WATER"},
+                    {"symbol": "QUARTZ_SAND"}
+                ],
+                "expiration": "2025-06-23T03:05:12.102Z",
+                "size": "MODERATE"
+            })),
+        )
+        .await;
+
+        let client = Arc::new(SpaceTradersClient::with_url(&mock_server.url(), None));
+
+        let mut ship = Ship::with_data(client.clone(), snake_ship());
+
+        let (actual, _) = ship.extract_with_survey(&some_survey()).await.unwrap();
+
+        assert_eq!(some_extraction(), actual);
+        assert_eq!(
+            Ship::with_data(client.clone(), snake_ship_after_extracting()),
+            ship
+        );
+    }
+
+    #[tokio::test]
+    async fn surveying_should_return_surveys_and_update_cooldown() {
+        let mock_server = MockServerBuilder::mock_once(
+            RequestMethod::Post,
+            "my/ships/SNAKE-1/survey",
+            201,
+            None,
+            None::<&()>,
+        )
+        .await;
+
+        let client = Arc::new(SpaceTradersClient::with_url(&mock_server.url(), None));
+
+        let mut ship = Ship::with_data(client.clone(), snake_ship());
+
+        let actual = ship.survey().await.unwrap();
+
+        assert_eq!(vec![some_survey(), some_other_survey()], actual);
+        assert_eq!(
+            69,
+            ship.get_data().await.unwrap().cooldown.remaining_seconds
+        );
+    }
+
+    #[tokio::test]
+    async fn surveying_without_cached_data_should_not_panic() {
+        let mock_server = MockServerBuilder::mock_once(
+            RequestMethod::Post,
+            "my/ships/SNAKE-1/survey",
+            201,
+            None,
+            None::<&()>,
+        )
+        .await;
+
+        let client = Arc::new(SpaceTradersClient::with_url(&mock_server.url(), None));
+
+        let mut ship = Ship::new(client, "SNAKE-1");
+
+        let actual = ship.survey().await.unwrap();
+
+        assert_eq!(vec![some_survey(), some_other_survey()], actual);
+    }
+
+    #[tokio::test]
+    async fn should_siphon_resources() {
+        let mock_server = MockServerBuilder::mock_once(
+            RequestMethod::Post,
+            "my/ships/SNAKE-1/siphon",
+            201,
+            None,
+            None::<&()>,
+        )
+        .await;
+
+        let client = Arc::new(SpaceTradersClient::with_url(&mock_server.url(), None));
+
+        let mut ship = Ship::with_data(client, snake_ship());
+
+        let (siphon, events) = ship.siphon().await.unwrap();
+
+        assert_eq!(
+            Siphon {
+                ship_symbol: string!("SNAKE-1"),
+                r#yield: SiphonYield {
+                    symbol: TradeSymbol::LiquidHydrogen,
+                    units: 7,
+                },
+            },
+            siphon
+        );
+        assert_eq!(ShipConditionEventSymbol::ReactorOverload, events[0].symbol);
+
+        let data = ship.get_data().await.unwrap();
+        assert_eq!(7, data.cargo.units);
+        assert_eq!(69, data.cooldown.remaining_seconds);
+    }
+
+    #[tokio::test]
+    async fn siphoning_without_a_gas_siphon_should_fail_without_a_request() {
+        let client = Arc::new(SpaceTradersClient::with_url("http://127.0.0.1:1", None));
+
+        let mut ship = Ship::with_data(
+            client,
+            ShipData {
+                mounts: vec![some_mining_laser_2()],
+                ..snake_ship()
+            },
+        );
+
+        let actual = ship.siphon().await.unwrap_err();
+
+        assert_eq!(Some(&ErrorCode::ShipMissingGasSiphonsError), actual.code());
+    }
+
+    #[tokio::test]
+    async fn siphoning_should_only_need_a_gas_siphon() {
+        let mock_server = MockServerBuilder::mock_once(
+            RequestMethod::Post,
+            "my/ships/SNAKE-1/siphon",
+            201,
+            None,
+            None::<&()>,
+        )
+        .await;
+
+        let client = Arc::new(SpaceTradersClient::with_url(&mock_server.url(), None));
+
+        let mut ship = Ship::with_data(
+            client,
+            ShipData {
+                modules: vec![some_cargo_hold_2()],
+                ..snake_ship()
+            },
+        );
+
+        let (siphon, _) = ship.siphon().await.unwrap();
+
+        assert_eq!(7, siphon.r#yield.units);
+    }
+
+    #[tokio::test]
+    async fn should_refine_cargo() {
+        let mock_server = MockServerBuilder::mock_once(
+            RequestMethod::Post,
+            "my/ships/SNAKE-1/refine",
+            201,
+            None,
+            Some(&json!({"produce": "IRON"})),
+        )
+        .await;
+
+        let client = Arc::new(SpaceTradersClient::with_url(&mock_server.url(), None));
+
+        let mut ship = Ship::with_data(
+            client,
+            ShipData {
+                modules: vec![some_cargo_hold_2(), some_ore_refinery()],
+                ..snake_ship()
+            },
+        );
+
+        let actual = ship.refine(TradeSymbol::Iron).await.unwrap();
+
+        let expected = Refinement {
+            produced: vec![RefinementYield {
+                trade_symbol: TradeSymbol::Iron,
+                units: 10,
+            }],
+            consumed: vec![RefinementYield {
+                trade_symbol: TradeSymbol::IronOre,
+                units: 30,
+            }],
+        };
+
+        assert_eq!(expected, actual);
+
+        let data = ship.get_data().await.unwrap();
+        assert_eq!(TradeSymbol::Iron, data.cargo.inventory[0].symbol);
+        assert_eq!(59, data.cooldown.remaining_seconds);
+    }
+
+    #[tokio::test]
+    async fn should_refine_cargo_with_a_gas_processor() {
+        let mock_server = MockServerBuilder::mock_once(
+            RequestMethod::Post,
+            "my/ships/SNAKE-1/refine",
+            201,
+            None,
+            Some(&json!({"produce": "IRON"})),
+        )
+        .await;
+
+        let client = Arc::new(SpaceTradersClient::with_url(&mock_server.url(), None));
+
+        let mut ship = Ship::with_data(
+            client,
+            ShipData {
+                modules: vec![some_cargo_hold_2(), some_gas_processor()],
+                ..snake_ship()
+            },
+        );
+
+        assert!(ship.refine(TradeSymbol::Iron).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn refining_without_a_refinery_should_fail_without_a_request() {
+        let client = Arc::new(SpaceTradersClient::with_url("http://127.0.0.1:1", None));
+
+        let mut ship = Ship::with_data(
+            client,
+            ShipData {
+                modules: vec![some_cargo_hold_2()],
+                ..snake_ship()
+            },
+        );
+
+        let actual = ship.refine(TradeSymbol::Iron).await.unwrap_err();
+
+        assert!(matches!(
+            actual,
+            Error::Precondition {
+                code: ErrorCode::ShipMissingRefineryError,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn condition_event_history_should_be_bounded() {
+        let mut ship = Ship::with_data(Arc::new(SpaceTradersClient::new(None)), snake_ship());
+        let event = |symbol| ShipConditionEvent {
+            symbol,
+            component: ShipComponent::Engine,
+            name: string!("Thruster Nozzle Wear"),
+            description: string!("Wear on the thruster nozzles."),
+        };
+
+        ship.record_condition_events(&vec![
+            event(ShipConditionEventSymbol::ThrusterNozzleWear);
+            CONDITION_EVENT_HISTORY
+        ]);
+        ship.record_condition_events(&[event(ShipConditionEventSymbol::ReactorOverload)]);
+
+        assert_eq!(CONDITION_EVENT_HISTORY, ship.condition_events().len());
+        assert_eq!(
+            ShipConditionEventSymbol::ReactorOverload,
+            ship.condition_events().back().unwrap().symbol
+        );
+    }
+
     #[tokio::test]
     async fn should_jettison_resources() {
         let mock_server = MockServerBuilder::mock_once(
@@ -686,7 +1316,7 @@ pub mod tests {
 
         let mut ship = Ship::with_data(client.clone(), snake_ship_after_extracting());
 
-        let _ = ship.jettison(TradeSymbol::IceWater, 4).await.unwrap();
+        ship.jettison(TradeSymbol::IceWater, 4).await.unwrap();
 
         let actual_ship = ship;
 
@@ -695,6 +1325,27 @@ pub mod tests {
         assert_eq!(expected_ship, actual_ship);
     }
 
+    #[tokio::test]
+    async fn should_negotiate_contract() {
+        let mock_server = MockServerBuilder::mock_once::<serde_json::Value>(
+            RequestMethod::Post,
+            "my/ships/BADGER-1/negotiate/contract",
+            201,
+            None,
+            None,
+        )
+        .await;
+
+        let client = Arc::new(SpaceTradersClient::with_url(&mock_server.url(), None));
+
+        let mut ship = Ship::with_data(client, some_ship());
+
+        let actual = ship.negotiate_contract().await.unwrap();
+
+        assert_eq!("cmbh2k9xq01c2s60jd5f8r3wz", actual.data.id);
+        assert!(!actual.is_accepted());
+    }
+
     #[test]
     fn should_be_deserializable() {
         let json_str = r#"
@@ -1024,4 +1675,27 @@ pub mod tests {
 
         assert_eq!(expected, actual);
     }
+
+    #[test]
+    fn unknown_ship_condition_event_symbol_should_round_trip() {
+        let json_str = r#""HULL_BREACH""#;
+
+        let actual: ShipConditionEventSymbol = serde_json::from_str(json_str).unwrap();
+
+        assert_eq!(
+            ShipConditionEventSymbol::Unknown(string!("HULL_BREACH")),
+            actual
+        );
+        assert_eq!(json_str, serde_json::to_string(&actual).unwrap());
+    }
+
+    #[test]
+    fn unknown_ship_component_should_round_trip() {
+        let json_str = r#""MODULE""#;
+
+        let actual: ShipComponent = serde_json::from_str(json_str).unwrap();
+
+        assert_eq!(ShipComponent::Unknown(string!("MODULE")), actual);
+        assert_eq!(json_str, serde_json::to_string(&actual).unwrap());
+    }
 }
@@ -1,4 +1,4 @@
-use serde_derive::Deserialize;
+use serde_derive::{Deserialize, Serialize};
 
 use super::Requirements;
 
@@ -13,7 +13,7 @@ pub struct Module {
     pub requirements: Requirements,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum ModuleType {
     #[serde(rename = "MODULE_MINERAL_PROCESSOR_I")]
     MineralProcessorI,
@@ -55,6 +55,21 @@ pub enum ModuleType {
     ShieldGeneratorI,
     #[serde(rename = "MODULE_SHIELD_GENERATOR_II")]
     ShieldGeneratorII,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl ModuleType {
+    pub fn is_gas_processor(&self) -> bool {
+        matches!(self, ModuleType::GasProcessorI)
+    }
+
+    pub fn is_refinery(&self) -> bool {
+        matches!(
+            self,
+            ModuleType::MicroRefineryI | ModuleType::OreRefineryI | ModuleType::FuelRefineryI
+        )
+    }
 }
 
 #[cfg(test)]
@@ -279,6 +294,23 @@ pub mod tests {
         assert_eq!(expected, actual);
     }
 
+    pub fn some_ore_refinery() -> Module {
+        Module {
+            symbol: ModuleType::OreRefineryI,
+            capacity: None,
+            range: None,
+            name: string!("Ore Refinery"),
+            description: string!(
+                "A specialized module that can refine raw ores into usable metals and other materials."
+            ),
+            requirements: Requirements {
+                power: Some(1),
+                crew: Some(0),
+                slots: Some(1),
+            },
+        }
+    }
+
     pub fn some_gas_processor() -> Module {
         Module {
             symbol: ModuleType::GasProcessorI,
@@ -315,4 +347,14 @@ pub mod tests {
 
         assert_eq!(expected, actual);
     }
+
+    #[test]
+    fn unknown_module_type_should_round_trip() {
+        let json_str = r#""MODULE_CARGO_HOLD_IV""#;
+
+        let actual: ModuleType = serde_json::from_str(json_str).unwrap();
+
+        assert_eq!(ModuleType::Unknown(string!("MODULE_CARGO_HOLD_IV")), actual);
+        assert_eq!(json_str, serde_json::to_string(&actual).unwrap());
+    }
 }
@@ -1,4 +1,4 @@
-use serde_derive::Deserialize;
+use serde_derive::{Deserialize, Serialize};
 
 use super::Requirements;
 
@@ -13,7 +13,7 @@ pub struct Mount {
     pub requirements: Requirements,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum MountType {
     #[serde(rename = "MOUNT_GAS_SIPHON_I")]
     GasSiphonI,
@@ -45,9 +45,20 @@ pub enum MountType {
     MissileLauncherI,
     #[serde(rename = "MOUNT_TURRET_I")]
     TurretI,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+impl MountType {
+    pub fn is_gas_siphon(&self) -> bool {
+        matches!(
+            self,
+            MountType::GasSiphonI | MountType::GasSiphonII | MountType::GasSiphonIII
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum ResourceType {
     QuartzSand,
@@ -64,6 +75,8 @@ pub enum ResourceType {
     Diamonds,
     UraniteOre,
     MeritiumOre,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 #[cfg(test)]
@@ -369,4 +382,24 @@ pub mod tests {
 
         assert_eq!(expected, actual);
     }
+
+    #[test]
+    fn unknown_mount_type_should_round_trip() {
+        let json_str = r#""MOUNT_LASER_CANNON_II""#;
+
+        let actual: MountType = serde_json::from_str(json_str).unwrap();
+
+        assert_eq!(MountType::Unknown(string!("MOUNT_LASER_CANNON_II")), actual);
+        assert_eq!(json_str, serde_json::to_string(&actual).unwrap());
+    }
+
+    #[test]
+    fn unknown_resource_type_should_round_trip() {
+        let json_str = r#""HELIUM_ICE""#;
+
+        let actual: ResourceType = serde_json::from_str(json_str).unwrap();
+
+        assert_eq!(ResourceType::Unknown(string!("HELIUM_ICE")), actual);
+        assert_eq!(json_str, serde_json::to_string(&actual).unwrap());
+    }
 }
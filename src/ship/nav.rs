@@ -1,4 +1,4 @@
-use serde_derive::Deserialize;
+use serde_derive::{Deserialize, Serialize};
 
 use crate::system::waypoint::WaypointType;
 
@@ -32,21 +32,25 @@ pub struct Location {
     pub y: i32,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum ShipStatus {
     InTransit,
     InOrbit,
     Docked,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "UPPERCASE")]
 pub enum FlightMode {
     Drift,
     Stealth,
     Cruise,
     Burn,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 #[cfg(test)]
@@ -328,5 +332,25 @@ pub mod tests {
 
             assert_eq!(expected, actual);
         }
+
+        #[test]
+        fn unknown_ship_status_should_round_trip() {
+            let json_str = r#""IN_REPAIR""#;
+
+            let actual: ShipStatus = serde_json::from_str(json_str).unwrap();
+
+            assert_eq!(ShipStatus::Unknown(string!("IN_REPAIR")), actual);
+            assert_eq!(json_str, serde_json::to_string(&actual).unwrap());
+        }
+
+        #[test]
+        fn unknown_flight_mode_should_round_trip() {
+            let json_str = r#""WARP""#;
+
+            let actual: FlightMode = serde_json::from_str(json_str).unwrap();
+
+            assert_eq!(FlightMode::Unknown(string!("WARP")), actual);
+            assert_eq!(json_str, serde_json::to_string(&actual).unwrap());
+        }
     }
 }
@@ -1,4 +1,4 @@
-use serde_derive::Deserialize;
+use serde_derive::{Deserialize, Serialize};
 
 use super::Requirements;
 
@@ -13,7 +13,7 @@ pub struct Reactor {
     pub requirements: Requirements,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum ReactorType {
     #[serde(rename = "REACTOR_SOLAR_I")]
     SolarI,
@@ -25,6 +25,8 @@ pub enum ReactorType {
     ChemicalI,
     #[serde(rename = "REACTOR_ANTIMATTER_I")]
     AntimatterI,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 #[cfg(test)]
@@ -107,4 +109,14 @@ pub mod tests {
 
         assert_eq!(expected, actual);
     }
+
+    #[test]
+    fn unknown_reactor_type_should_round_trip() {
+        let json_str = r#""REACTOR_FUSION_II""#;
+
+        let actual: ReactorType = serde_json::from_str(json_str).unwrap();
+
+        assert_eq!(ReactorType::Unknown(string!("REACTOR_FUSION_II")), actual);
+        assert_eq!(json_str, serde_json::to_string(&actual).unwrap());
+    }
 }
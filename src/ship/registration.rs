@@ -1,4 +1,4 @@
-use serde_derive::Deserialize;
+use serde_derive::{Deserialize, Serialize};
 
 use crate::faction::Factions;
 
@@ -10,7 +10,7 @@ pub struct Registration {
     pub role: ShipRole,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "UPPERCASE")]
 pub enum ShipRole {
     Fabricator,
@@ -27,6 +27,8 @@ pub enum ShipRole {
     Satellite,
     Explorer,
     Refinery,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 #[cfg(test)]
@@ -79,4 +81,14 @@ pub mod tests {
 
         assert_eq!(expected, actual);
     }
+
+    #[test]
+    fn unknown_ship_role_should_round_trip() {
+        let json_str = r#""SALVAGER""#;
+
+        let actual: ShipRole = serde_json::from_str(json_str).unwrap();
+
+        assert_eq!(ShipRole::Unknown(string!("SALVAGER")), actual);
+        assert_eq!(json_str, serde_json::to_string(&actual).unwrap());
+    }
 }
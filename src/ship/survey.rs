@@ -0,0 +1,262 @@
+use std::collections::HashMap;
+
+use serde_derive::{Deserialize, Serialize};
+
+use crate::{
+    space_traders_client::{Error, ErrorCode},
+    timestamp::Timestamp,
+};
+
+use super::TradeSymbol;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Survey {
+    pub signature: String,
+    pub symbol: String,
+    pub deposits: Vec<SurveyDeposit>,
+    pub expiration: Timestamp,
+    pub size: SurveySize,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SurveyDeposit {
+    pub symbol: TradeSymbol,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SurveySize {
+    Small,
+    Moderate,
+    Large,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl Survey {
+    pub fn share_of(&self, trade_symbol: &TradeSymbol) -> f64 {
+        if self.deposits.is_empty() {
+            return 0.0;
+        }
+
+        let matching = self
+            .deposits
+            .iter()
+            .filter(|deposit| deposit.symbol == *trade_symbol)
+            .count();
+
+        matching as f64 / self.deposits.len() as f64
+    }
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct SurveyBook {
+    surveys: HashMap<String, Vec<Survey>>,
+}
+
+impl SurveyBook {
+    pub fn new() -> Self {
+        SurveyBook::default()
+    }
+
+    pub fn add(&mut self, surveys: impl IntoIterator<Item = Survey>) {
+        for survey in surveys {
+            let waypoint_surveys = self.surveys.entry(survey.symbol.clone()).or_default();
+            waypoint_surveys.retain(|known| known.signature != survey.signature);
+            waypoint_surveys.push(survey);
+        }
+    }
+
+    pub fn surveys(&self, waypoint_symbol: &str) -> &[Survey] {
+        self.surveys
+            .get(waypoint_symbol)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    pub fn remove(&mut self, survey: &Survey) -> Option<Survey> {
+        let waypoint_surveys = self.surveys.get_mut(&survey.symbol)?;
+        let index = waypoint_surveys
+            .iter()
+            .position(|known| known.signature == survey.signature)?;
+
+        Some(waypoint_surveys.remove(index))
+    }
+
+    // Returns true when the error means the survey can't be used again and it was dropped.
+    pub fn handle_error(&mut self, survey: &Survey, error: &Error) -> bool {
+        match error.code() {
+            Some(ErrorCode::ShipSurveyExpirationError | ErrorCode::ShipSurveyExhaustedError) => {
+                self.remove(survey).is_some()
+            }
+            _ => false,
+        }
+    }
+
+    // Surveys with an expiration that can't be parsed are kept, the server still decides.
+    #[cfg(feature = "chrono")]
+    pub fn remove_expired(&mut self, now: chrono::DateTime<chrono::Utc>) {
+        for waypoint_surveys in self.surveys.values_mut() {
+            waypoint_surveys.retain(|survey| {
+                survey
+                    .expiration
+                    .to_date_time()
+                    .is_none_or(|expiration| expiration > now)
+            });
+        }
+    }
+
+    // Surveys at the waypoint that contain the good, the highest share first.
+    pub fn ranked(&self, waypoint_symbol: &str, trade_symbol: &TradeSymbol) -> Vec<&Survey> {
+        let mut ranked: Vec<&Survey> = self
+            .surveys(waypoint_symbol)
+            .iter()
+            .filter(|survey| survey.share_of(trade_symbol) > 0.0)
+            .collect();
+
+        ranked.sort_by(|a, b| {
+            b.share_of(trade_symbol)
+                .total_cmp(&a.share_of(trade_symbol))
+        });
+
+        ranked
+    }
+
+    pub fn best(&self, waypoint_symbol: &str, trade_symbol: &TradeSymbol) -> Option<&Survey> {
+        self.ranked(waypoint_symbol, trade_symbol)
+            .into_iter()
+            .next()
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use crate::{space_traders_client::ErrorData, string};
+
+    pub fn some_survey() -> Survey {
+        Survey {
+            signature: string!("X1-CB91-A1-4F2A1C"),
+            symbol: string!("X1-CB91-A1"),
+            deposits: vec![
+                SurveyDeposit {
+                    symbol: TradeSymbol::IceWater,
+                },
+                SurveyDeposit {
+                    symbol: TradeSymbol::IceWater,
+                },
+                SurveyDeposit {
+                    symbol: TradeSymbol::QuartzSand,
+                },
+            ],
+            expiration: "2025-06-23T03:05:12.102Z".parse().unwrap(),
+            size: SurveySize::Moderate,
+        }
+    }
+
+    pub fn some_other_survey() -> Survey {
+        Survey {
+            signature: string!("X1-CB91-A1-9B03D7"),
+            symbol: string!("X1-CB91-A1"),
+            deposits: vec![
+                SurveyDeposit {
+                    symbol: TradeSymbol::SiliconCrystals,
+                },
+                SurveyDeposit {
+                    symbol: TradeSymbol::IceWater,
+                },
+            ],
+            expiration: "2025-06-23T02:58:40.871Z".parse().unwrap(),
+            size: SurveySize::Small,
+        }
+    }
+
+    fn api_error(code: ErrorCode) -> Error {
+        Error::Api(ErrorData {
+            code,
+            message: string!("Survey can no longer be used."),
+            data: None,
+            request_id: None,
+        })
+    }
+
+    fn some_survey_book() -> SurveyBook {
+        let mut book = SurveyBook::new();
+        book.add(vec![some_other_survey(), some_survey()]);
+        book
+    }
+
+    #[test]
+    fn should_rank_surveys_by_share_of_the_target_good() {
+        let book = some_survey_book();
+
+        assert_eq!(
+            vec![&some_survey(), &some_other_survey()],
+            book.ranked("X1-CB91-A1", &TradeSymbol::IceWater)
+        );
+        assert_eq!(
+            Some(&some_other_survey()),
+            book.best("X1-CB91-A1", &TradeSymbol::SiliconCrystals)
+        );
+        assert_eq!(None, book.best("X1-CB91-A1", &TradeSymbol::IronOre));
+        assert_eq!(None, book.best("X1-CB91-B2", &TradeSymbol::IceWater));
+    }
+
+    #[test]
+    fn adding_a_known_survey_should_replace_it() {
+        let mut book = some_survey_book();
+
+        book.add(vec![some_survey()]);
+
+        assert_eq!(2, book.surveys("X1-CB91-A1").len());
+    }
+
+    #[test]
+    fn should_drop_exhausted_and_expired_surveys() {
+        let mut book = some_survey_book();
+
+        assert!(book.handle_error(
+            &some_survey(),
+            &api_error(ErrorCode::ShipSurveyExhaustedError)
+        ));
+        assert!(book.handle_error(
+            &some_other_survey(),
+            &api_error(ErrorCode::ShipSurveyExpirationError)
+        ));
+
+        assert!(book.surveys("X1-CB91-A1").is_empty());
+    }
+
+    #[test]
+    fn should_keep_surveys_on_unrelated_errors() {
+        let mut book = some_survey_book();
+
+        assert!(!book.handle_error(
+            &some_survey(),
+            &api_error(ErrorCode::ShipExtractInvalidSurveyLocationError)
+        ));
+
+        assert_eq!(2, book.surveys("X1-CB91-A1").len());
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn should_remove_expired_surveys() {
+        let mut book = some_survey_book();
+
+        book.remove_expired("2025-06-23T03:00:00.000Z".parse().unwrap());
+
+        assert_eq!(vec![some_survey()], book.surveys("X1-CB91-A1"));
+    }
+
+    #[test]
+    fn unknown_survey_size_should_round_trip() {
+        let json_str = r#""HUGE""#;
+
+        let actual: SurveySize = serde_json::from_str(json_str).unwrap();
+
+        assert_eq!(SurveySize::Unknown(string!("HUGE")), actual);
+        assert_eq!(json_str, serde_json::to_string(&actual).unwrap());
+    }
+}
@@ -0,0 +1,513 @@
+use std::fmt;
+
+use serde_derive::{Deserialize, Serialize};
+
+use crate::{
+    agent::AgentData,
+    space_traders_client::{Error, ErrorCode},
+    system::waypoint::{
+        market::{Market, MarketTransaction},
+        Waypoint,
+    },
+};
+
+use super::{Cargo, Fuel, Ship, TradeSymbol};
+
+#[derive(Serialize)]
+struct TradeRequest {
+    symbol: TradeSymbol,
+    units: i32,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct TradeData {
+    agent: AgentData,
+    cargo: Cargo,
+    transaction: MarketTransaction,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct TradeResponse {
+    data: TradeData,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct RefuelRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    units: Option<i32>,
+    from_cargo: bool,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct RefuelData {
+    agent: AgentData,
+    fuel: Fuel,
+    cargo: Option<Cargo>,
+    transaction: MarketTransaction,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct RefuelResponse {
+    data: RefuelData,
+}
+
+// Returned when a trade fails. For an order split into several transactions, the ones that went
+// through are kept, aggregated, with the agent data from the last of them.
+#[derive(Debug)]
+pub struct PartialTrade {
+    pub completed: Option<(MarketTransaction, AgentData)>,
+    pub error: Error,
+}
+
+impl PartialTrade {
+    fn failed(error: Error) -> Self {
+        PartialTrade {
+            completed: None,
+            error,
+        }
+    }
+}
+
+impl fmt::Display for PartialTrade {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.completed {
+            Some((transaction, _)) => write!(
+                f,
+                "{} (after trading {} units)",
+                self.error, transaction.units
+            ),
+            None => write!(f, "{}", self.error),
+        }
+    }
+}
+
+impl std::error::Error for PartialTrade {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+impl From<PartialTrade> for Error {
+    fn from(partial: PartialTrade) -> Self {
+        partial.error
+    }
+}
+
+impl Ship {
+    // An order over the good's trade volume is refused by the server, it is then split into
+    // several transactions using the trade volume at the ship's current market.
+    pub async fn purchase_cargo(
+        &mut self,
+        symbol: TradeSymbol,
+        units: i32,
+    ) -> Result<(MarketTransaction, AgentData), PartialTrade> {
+        self.trade("purchase", symbol, units, None).await
+    }
+
+    pub async fn sell_cargo(
+        &mut self,
+        symbol: TradeSymbol,
+        units: i32,
+    ) -> Result<(MarketTransaction, AgentData), PartialTrade> {
+        self.trade("sell", symbol, units, None).await
+    }
+
+    // Splits up front with the trade volume from an already fetched market, saving the refused
+    // request.
+    pub async fn purchase_cargo_split(
+        &mut self,
+        symbol: TradeSymbol,
+        units: i32,
+        market: &Market,
+    ) -> Result<(MarketTransaction, AgentData), PartialTrade> {
+        let trade_volume = trade_volume(market, &symbol);
+        self.trade("purchase", symbol, units, trade_volume).await
+    }
+
+    pub async fn sell_cargo_split(
+        &mut self,
+        symbol: TradeSymbol,
+        units: i32,
+        market: &Market,
+    ) -> Result<(MarketTransaction, AgentData), PartialTrade> {
+        let trade_volume = trade_volume(market, &symbol);
+        self.trade("sell", symbol, units, trade_volume).await
+    }
+
+    pub async fn refuel(
+        &mut self,
+        units: Option<i32>,
+        from_cargo: bool,
+    ) -> Result<(MarketTransaction, AgentData), Error> {
+        let response: RefuelResponse = self
+            .client
+            .post_with_body(
+                &format!("my/ships/{}/refuel", self.symbol),
+                &RefuelRequest { units, from_cargo },
+                reqwest::StatusCode::OK,
+            )
+            .await?;
+
+        self.update_fuel(response.data.fuel);
+        if let Some(cargo) = response.data.cargo {
+            self.update_cargo(cargo);
+        }
+
+        Ok((response.data.transaction, response.data.agent))
+    }
+
+    async fn trade(
+        &mut self,
+        action: &str,
+        symbol: TradeSymbol,
+        units: i32,
+        trade_volume: Option<i32>,
+    ) -> Result<(MarketTransaction, AgentData), PartialTrade> {
+        if units <= 0 {
+            return Err(PartialTrade::failed(Error::InvalidRequest {
+                endpoint: format!("my/ships/{}/{}", self.symbol, action),
+                message: format!("Cannot trade {} units of {:?}", units, symbol),
+            }));
+        }
+
+        if let Some(trade_volume) = trade_volume.filter(|trade_volume| units > *trade_volume) {
+            return self
+                .trade_in_parts(action, symbol, units, trade_volume)
+                .await;
+        }
+
+        match self.send_trade(action, symbol.clone(), units).await {
+            Err(error) if error.code() == Some(&ErrorCode::MarketTradeUnitLimitError) => {
+                match self.market_trade_volume(&symbol).await {
+                    Ok(Some(trade_volume)) if units > trade_volume => {
+                        self.trade_in_parts(action, symbol, units, trade_volume)
+                            .await
+                    }
+                    _ => Err(PartialTrade::failed(error)),
+                }
+            }
+            result => result.map_err(PartialTrade::failed),
+        }
+    }
+
+    // Stops at the first failed request, the cached cargo reflects the transactions before it.
+    async fn trade_in_parts(
+        &mut self,
+        action: &str,
+        symbol: TradeSymbol,
+        units: i32,
+        trade_volume: i32,
+    ) -> Result<(MarketTransaction, AgentData), PartialTrade> {
+        let mut remaining = units;
+        let first = remaining.min(trade_volume);
+        let (mut transaction, mut agent) = self
+            .send_trade(action, symbol.clone(), first)
+            .await
+            .map_err(PartialTrade::failed)?;
+        remaining -= first;
+
+        while remaining > 0 {
+            let part = remaining.min(trade_volume);
+
+            match self.send_trade(action, symbol.clone(), part).await {
+                Ok((next, next_agent)) => {
+                    transaction = merge(transaction, next);
+                    agent = next_agent;
+                }
+                Err(error) => {
+                    return Err(PartialTrade {
+                        completed: Some((transaction, agent)),
+                        error,
+                    })
+                }
+            }
+
+            remaining -= part;
+        }
+
+        Ok((transaction, agent))
+    }
+
+    async fn send_trade(
+        &mut self,
+        action: &str,
+        symbol: TradeSymbol,
+        units: i32,
+    ) -> Result<(MarketTransaction, AgentData), Error> {
+        let response: TradeResponse = self
+            .client
+            .post_with_body(
+                &format!("my/ships/{}/{}", self.symbol, action),
+                &TradeRequest { symbol, units },
+                reqwest::StatusCode::CREATED,
+            )
+            .await?;
+
+        self.update_cargo(response.data.cargo);
+
+        Ok((response.data.transaction, response.data.agent))
+    }
+
+    async fn market_trade_volume(&mut self, symbol: &TradeSymbol) -> Result<Option<i32>, Error> {
+        let nav = self.get_data().await?.nav;
+        let market =
+            Waypoint::get_waypoint_market(&self.client, &nav.system_symbol, &nav.waypoint_symbol)
+                .await?;
+
+        Ok(trade_volume(&market, symbol))
+    }
+}
+
+// A non-positive trade volume can't be used to split an order and is treated as unknown.
+fn trade_volume(market: &Market, symbol: &TradeSymbol) -> Option<i32> {
+    market
+        .trade_goods
+        .iter()
+        .flatten()
+        .find(|good| good.symbol == *symbol)
+        .map(|good| good.trade_volume)
+        .filter(|trade_volume| *trade_volume > 0)
+}
+
+fn merge(total: MarketTransaction, next: MarketTransaction) -> MarketTransaction {
+    let units = total.units + next.units;
+    let total_price = total.total_price + next.total_price;
+
+    MarketTransaction {
+        units,
+        total_price,
+        price_per_unit: if units > 0 { total_price / units } else { 0 },
+        ..next
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use std::sync::Arc;
+
+    use mock_server::{MockServerBuilder, RequestMethod};
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        ship::{tests::some_ship, InventoryItem, ShipData},
+        space_traders_client::SpaceTradersClient,
+        string,
+        system::waypoint::{market::TransactionType, Waypoint},
+    };
+
+    fn snake_ship_at_market(cargo: Cargo) -> ShipData {
+        let mut data = some_ship();
+        data.symbol = string!("SNAKE-1");
+        data.nav.system_symbol = string!("X1-CB91");
+        data.nav.waypoint_symbol = string!("X1-CB91-A1");
+        data.cargo = cargo;
+        data
+    }
+
+    async fn market_mock() -> MockServerBuilder {
+        MockServerBuilder::new()
+            .await
+            .mock_response(
+                RequestMethod::Get,
+                "systems/X1-CB91/waypoints/X1-CB91-A1/market",
+                200,
+                None,
+                None::<&()>,
+            )
+            .await
+            .mock_response(
+                RequestMethod::Post,
+                "my/ships/SNAKE-1/purchase",
+                201,
+                None,
+                Some(&json!({"symbol": "IRON_ORE", "units": 20})),
+            )
+            .await
+    }
+
+    fn empty_cargo() -> Cargo {
+        Cargo {
+            capacity: 40,
+            units: 0,
+            inventory: vec![],
+        }
+    }
+
+    #[tokio::test]
+    async fn large_purchase_should_be_split_when_refused_over_the_trade_volume() {
+        let mock_server = market_mock()
+            .await
+            .mock_response(
+                RequestMethod::Post,
+                "my/ships/SNAKE-1/purchase",
+                400,
+                None,
+                Some(&json!({"symbol": "IRON_ORE", "units": 40})),
+            )
+            .await
+            .build();
+
+        let client = Arc::new(SpaceTradersClient::with_url(&mock_server.url(), None));
+        let mut ship = Ship::with_data(client, snake_ship_at_market(empty_cargo()));
+
+        let (transaction, agent) = ship.purchase_cargo(TradeSymbol::IronOre, 40).await.unwrap();
+
+        assert_eq!(40, transaction.units);
+        assert_eq!(2000, transaction.total_price);
+        assert_eq!(173000, agent.credits);
+    }
+
+    #[tokio::test]
+    async fn purchase_without_units_should_fail_without_a_request() {
+        let client = Arc::new(SpaceTradersClient::with_url("http://127.0.0.1:1", None));
+        let mut ship = Ship::with_data(client, snake_ship_at_market(empty_cargo()));
+
+        let actual = ship
+            .purchase_cargo(TradeSymbol::IronOre, 0)
+            .await
+            .unwrap_err();
+
+        assert!(actual.completed.is_none());
+        assert!(matches!(actual.error, Error::InvalidRequest { .. }));
+    }
+
+    #[tokio::test]
+    async fn should_purchase_cargo_in_a_single_request() {
+        let mock_server = MockServerBuilder::mock_once(
+            RequestMethod::Post,
+            "my/ships/SNAKE-1/purchase",
+            201,
+            None,
+            Some(&json!({"symbol": "IRON_ORE", "units": 20})),
+        )
+        .await;
+
+        let client = Arc::new(SpaceTradersClient::with_url(&mock_server.url(), None));
+        let mut ship = Ship::with_data(client, snake_ship_at_market(empty_cargo()));
+
+        let (transaction, agent) = ship.purchase_cargo(TradeSymbol::IronOre, 20).await.unwrap();
+
+        assert_eq!(20, transaction.units);
+        assert_eq!(173000, agent.credits);
+    }
+
+    #[tokio::test]
+    async fn large_purchase_should_be_split_and_aggregated() {
+        let mock_server = market_mock().await.build();
+
+        let client = Arc::new(SpaceTradersClient::with_url(&mock_server.url(), None));
+        let market = Waypoint::get_waypoint_market(&client, "X1-CB91", "X1-CB91-A1")
+            .await
+            .unwrap();
+        let mut ship = Ship::with_data(client, snake_ship_at_market(empty_cargo()));
+
+        let (transaction, agent) = ship
+            .purchase_cargo_split(TradeSymbol::IronOre, 40, &market)
+            .await
+            .unwrap();
+
+        assert_eq!(40, transaction.units);
+        assert_eq!(50, transaction.price_per_unit);
+        assert_eq!(2000, transaction.total_price);
+        assert_eq!(TransactionType::Purchase, transaction.transaction_type);
+        assert_eq!(173000, agent.credits);
+        assert_eq!(40, ship.get_data().await.unwrap().cargo.units);
+    }
+
+    #[tokio::test]
+    async fn failed_split_order_should_keep_the_completed_trades() {
+        let mock_server = market_mock().await.build();
+
+        let client = Arc::new(SpaceTradersClient::with_url(&mock_server.url(), None));
+        let market = Waypoint::get_waypoint_market(&client, "X1-CB91", "X1-CB91-A1")
+            .await
+            .unwrap();
+        let mut ship = Ship::with_data(client, snake_ship_at_market(empty_cargo()));
+
+        let actual = ship
+            .purchase_cargo_split(TradeSymbol::IronOre, 45, &market)
+            .await
+            .unwrap_err();
+
+        assert!(actual.to_string().contains("after trading 40 units"));
+        let (transaction, agent) = actual.completed.unwrap();
+        assert_eq!(40, transaction.units);
+        assert_eq!(173000, agent.credits);
+        assert_eq!(40, ship.get_data().await.unwrap().cargo.units);
+    }
+
+    #[tokio::test]
+    async fn should_sell_cargo() {
+        let mock_server = MockServerBuilder::mock_once(
+            RequestMethod::Post,
+            "my/ships/SNAKE-1/sell",
+            201,
+            None,
+            Some(&json!({"symbol": "ICE_WATER", "units": 4})),
+        )
+        .await;
+
+        let client = Arc::new(SpaceTradersClient::with_url(&mock_server.url(), None));
+        let mut ship = Ship::with_data(
+            client,
+            snake_ship_at_market(Cargo {
+                capacity: 40,
+                units: 4,
+                inventory: vec![InventoryItem {
+                    symbol: TradeSymbol::IceWater,
+                    name: string!("Fresh Water"),
+                    description: string!("High-quality fresh water."),
+                    units: 4,
+                }],
+            }),
+        );
+
+        let (transaction, agent) = ship.sell_cargo(TradeSymbol::IceWater, 4).await.unwrap();
+
+        assert_eq!(120, transaction.total_price);
+        assert_eq!(175120, agent.credits);
+        assert!(ship.get_data().await.unwrap().cargo.inventory.is_empty());
+    }
+
+    #[tokio::test]
+    async fn should_refuel() {
+        let mock_server = MockServerBuilder::mock_once(
+            RequestMethod::Post,
+            "my/ships/SNAKE-1/refuel",
+            200,
+            None,
+            Some(&json!({"units": 100, "fromCargo": false})),
+        )
+        .await;
+
+        let client = Arc::new(SpaceTradersClient::with_url(&mock_server.url(), None));
+        let mut data = snake_ship_at_market(empty_cargo());
+        data.fuel.current = 300;
+        let mut ship = Ship::with_data(client, data);
+
+        let (transaction, agent) = ship.refuel(Some(100), false).await.unwrap();
+
+        assert_eq!(TradeSymbol::Fuel, transaction.trade_symbol);
+        assert_eq!(174900, agent.credits);
+        assert_eq!(400, ship.get_data().await.unwrap().fuel.current);
+    }
+
+    #[tokio::test]
+    async fn refuelling_without_cached_data_should_not_panic() {
+        let mock_server = MockServerBuilder::mock_once(
+            RequestMethod::Post,
+            "my/ships/SNAKE-1/refuel",
+            200,
+            None,
+            Some(&json!({"units": 100, "fromCargo": false})),
+        )
+        .await;
+
+        let client = Arc::new(SpaceTradersClient::with_url(&mock_server.url(), None));
+        let mut ship = Ship::new(client, "SNAKE-1");
+
+        assert!(ship.refuel(Some(100), false).await.is_ok());
+    }
+}
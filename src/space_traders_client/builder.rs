@@ -0,0 +1,233 @@
+use std::{sync::Arc, time::Duration};
+
+use derivative::Derivative;
+use reqwest::header::HeaderMap;
+
+use super::{
+    Error, Middleware, RateLimit, RateLimiter, RetryPolicy, SpaceTradersClient, REAL_SERVER,
+};
+
+#[derive(Derivative, Default)]
+#[derivative(Debug)]
+pub struct SpaceTradersClientBuilder {
+    url: Option<String>,
+    token: Option<String>,
+    timeout: Option<Duration>,
+    connect_timeout: Option<Duration>,
+    user_agent: Option<String>,
+    proxy: Option<reqwest::Proxy>,
+    default_headers: HeaderMap,
+    http_client: Option<Arc<reqwest::Client>>,
+    rate_limit: Option<RateLimit>,
+    retry_policy: Option<RetryPolicy>,
+    #[derivative(Debug = "ignore")]
+    middlewares: Vec<Arc<dyn Middleware>>,
+}
+
+impl SpaceTradersClientBuilder {
+    pub fn new() -> Self {
+        SpaceTradersClientBuilder::default()
+    }
+
+    pub fn url(mut self, url: &str) -> Self {
+        self.url = Some(url.to_string());
+        self
+    }
+
+    pub fn token(mut self, token: &str) -> Self {
+        self.token = Some(token.to_string());
+        self
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub fn connect_timeout(mut self, connect_timeout: Duration) -> Self {
+        self.connect_timeout = Some(connect_timeout);
+        self
+    }
+
+    pub fn user_agent(mut self, user_agent: &str) -> Self {
+        self.user_agent = Some(user_agent.to_string());
+        self
+    }
+
+    pub fn proxy(mut self, proxy: reqwest::Proxy) -> Self {
+        self.proxy = Some(proxy);
+        self
+    }
+
+    pub fn default_headers(mut self, headers: HeaderMap) -> Self {
+        self.default_headers.extend(headers);
+        self
+    }
+
+    // Timeouts, user agent, proxy and default headers only apply to a client built here, an
+    // injected client is used exactly as it was configured.
+    pub fn http_client(mut self, client: Arc<reqwest::Client>) -> Self {
+        self.http_client = Some(client);
+        self
+    }
+
+    pub fn rate_limit(mut self, rate_limit: RateLimit) -> Self {
+        self.rate_limit = Some(rate_limit);
+        self
+    }
+
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(retry_policy);
+        self
+    }
+
+    pub fn middleware(mut self, middleware: impl Middleware + 'static) -> Self {
+        self.middlewares.push(Arc::new(middleware));
+        self
+    }
+
+    pub fn build(self) -> Result<SpaceTradersClient, Error> {
+        if let Some(rate_limit) = &self.rate_limit {
+            rate_limit.validate()?;
+        }
+
+        let client = match self.http_client {
+            Some(client) => client,
+            None => {
+                let mut builder = reqwest::Client::builder().default_headers(self.default_headers);
+
+                if let Some(timeout) = self.timeout {
+                    builder = builder.timeout(timeout);
+                }
+
+                if let Some(connect_timeout) = self.connect_timeout {
+                    builder = builder.connect_timeout(connect_timeout);
+                }
+
+                if let Some(user_agent) = self.user_agent {
+                    builder = builder.user_agent(user_agent);
+                }
+
+                if let Some(proxy) = self.proxy {
+                    builder = builder.proxy(proxy);
+                }
+
+                Arc::new(builder.build()?)
+            }
+        };
+
+        Ok(SpaceTradersClient {
+            client,
+            rate_limiter: Arc::new(RateLimiter::new(self.rate_limit.unwrap_or_default())),
+            middlewares: self.middlewares,
+            retry_policy: self.retry_policy.unwrap_or_default(),
+            url: self.url.unwrap_or_else(|| REAL_SERVER.to_string()),
+            token: self.token,
+        })
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use mock_server::{MockServerBuilder, RequestMethod};
+    use reqwest::header::HeaderValue;
+
+    use super::*;
+    use crate::string;
+
+    #[test]
+    fn should_default_to_the_real_server_without_a_token() {
+        let actual = SpaceTradersClientBuilder::new().build().unwrap();
+
+        let expected = SpaceTradersClient::new(None);
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn should_refuse_an_invalid_rate_limit() {
+        let actual = SpaceTradersClient::builder()
+            .rate_limit(RateLimit {
+                per_second: 0.0,
+                burst: 0,
+                burst_duration: Duration::from_secs(60),
+            })
+            .build()
+            .unwrap_err();
+
+        assert!(matches!(actual, Error::InvalidConfiguration(_)));
+    }
+
+    #[test]
+    fn should_use_the_given_url_and_token() {
+        let actual = SpaceTradersClient::builder()
+            .url("http://localhost:1234")
+            .token("token")
+            .build()
+            .unwrap();
+
+        let expected =
+            SpaceTradersClient::with_url("http://localhost:1234", Some(string!("token")));
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn should_share_an_injected_http_client() {
+        let http_client = Arc::new(reqwest::Client::new());
+
+        let first = SpaceTradersClient::builder()
+            .http_client(http_client.clone())
+            .build()
+            .unwrap();
+        let second = SpaceTradersClient::builder()
+            .http_client(http_client.clone())
+            .token("token")
+            .build()
+            .unwrap();
+
+        assert!(Arc::ptr_eq(&first.client, &second.client));
+    }
+
+    #[test]
+    fn should_return_an_error_for_an_invalid_user_agent() {
+        let actual = SpaceTradersClient::builder()
+            .user_agent("not\na valid header")
+            .build()
+            .unwrap_err();
+
+        assert!(matches!(actual, Error::Transport(_)));
+    }
+
+    #[tokio::test]
+    async fn should_send_requests_with_a_configured_client() {
+        let mock_server = MockServerBuilder::mock_once(
+            RequestMethod::Get,
+            "my/agent",
+            200,
+            Some(string!("token")),
+            None::<&()>,
+        )
+        .await;
+
+        let mut headers = HeaderMap::new();
+        headers.insert("x-fleet", HeaderValue::from_static("miners"));
+
+        let client = SpaceTradersClient::builder()
+            .url(&mock_server.url())
+            .token("token")
+            .timeout(Duration::from_secs(5))
+            .connect_timeout(Duration::from_secs(1))
+            .user_agent("space-traders-sdk-tests")
+            .default_headers(headers)
+            .build()
+            .unwrap();
+
+        let actual = client
+            .get::<(), serde_json::Value>("my/agent", None, reqwest::StatusCode::OK)
+            .await
+            .unwrap();
+
+        assert_eq!("BADGER", actual["data"]["symbol"]);
+    }
+}
@@ -0,0 +1,256 @@
+use std::time::Duration;
+
+use reqwest::{header::HeaderMap, Method, StatusCode};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ApiRequest {
+    pub method: Method,
+    pub endpoint: String,
+    pub query: Option<String>,
+    pub body: Option<serde_json::Value>,
+    pub headers: HeaderMap,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ApiResponse {
+    pub status: StatusCode,
+    pub headers: HeaderMap,
+    pub body: String,
+    pub elapsed: Duration,
+}
+
+impl ApiResponse {
+    pub fn new(status: StatusCode, body: &str) -> Self {
+        ApiResponse {
+            status,
+            headers: HeaderMap::new(),
+            body: body.to_string(),
+            elapsed: Duration::ZERO,
+        }
+    }
+}
+
+// Middlewares run in the order they were added on the way out and in reverse order on the way
+// back. Returning a response from `on_request` skips the network and every later middleware, the
+// earlier ones still see it in `on_response`. Hooks run once per attempt when a call is retried.
+pub trait Middleware: Send + Sync {
+    fn on_request(&self, _request: &mut ApiRequest) -> Option<ApiResponse> {
+        None
+    }
+
+    fn on_response(&self, _request: &ApiRequest, _response: &mut ApiResponse) {}
+
+    fn on_error(&self, _request: &ApiRequest, _error: &reqwest::Error, _elapsed: Duration) {}
+}
+
+#[cfg(test)]
+pub mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use mock_server::{MockServerBuilder, RequestMethod};
+    use reqwest::header::HeaderValue;
+
+    use super::*;
+    use crate::{
+        space_traders_client::{RetryPolicy, SpaceTradersClient},
+        string,
+    };
+
+    #[derive(Default)]
+    struct Recorder {
+        name: &'static str,
+        log: Arc<Mutex<Vec<String>>>,
+        canned: Option<ApiResponse>,
+    }
+
+    impl Middleware for Recorder {
+        fn on_request(&self, request: &mut ApiRequest) -> Option<ApiResponse> {
+            self.log.lock().unwrap().push(format!(
+                "{} request {} {}",
+                self.name, request.method, request.endpoint
+            ));
+            self.canned.clone()
+        }
+
+        fn on_response(&self, _request: &ApiRequest, response: &mut ApiResponse) {
+            self.log
+                .lock()
+                .unwrap()
+                .push(format!("{} response {}", self.name, response.status));
+        }
+
+        fn on_error(&self, request: &ApiRequest, _error: &reqwest::Error, _elapsed: Duration) {
+            self.log
+                .lock()
+                .unwrap()
+                .push(format!("{} error {}", self.name, request.endpoint));
+        }
+    }
+
+    struct InjectHeader;
+
+    impl Middleware for InjectHeader {
+        fn on_request(&self, request: &mut ApiRequest) -> Option<ApiResponse> {
+            request
+                .headers
+                .insert("x-fleet", HeaderValue::from_static("miners"));
+            None
+        }
+    }
+
+    struct Inspect(Arc<Mutex<Option<ApiRequest>>>);
+
+    impl Middleware for Inspect {
+        fn on_request(&self, request: &mut ApiRequest) -> Option<ApiResponse> {
+            *self.0.lock().unwrap() = Some(request.clone());
+            Some(ApiResponse::new(StatusCode::OK, "{}"))
+        }
+    }
+
+    #[tokio::test]
+    async fn should_run_middlewares_in_order_around_the_request() {
+        let mock_server = MockServerBuilder::mock_once(
+            RequestMethod::Get,
+            "my/agent",
+            200,
+            Some(string!("token")),
+            None::<&()>,
+        )
+        .await;
+        let log = Arc::new(Mutex::new(vec![]));
+
+        let client = SpaceTradersClient::with_url(&mock_server.url(), Some(string!("token")))
+            .with_middleware(Recorder {
+                name: "first",
+                log: log.clone(),
+                ..Recorder::default()
+            })
+            .with_middleware(Recorder {
+                name: "second",
+                log: log.clone(),
+                ..Recorder::default()
+            });
+
+        client
+            .get::<(), serde_json::Value>("my/agent", None, StatusCode::OK)
+            .await
+            .unwrap();
+
+        let expected = vec![
+            "first request GET my/agent",
+            "second request GET my/agent",
+            "second response 200 OK",
+            "first response 200 OK",
+        ];
+
+        assert_eq!(expected, *log.lock().unwrap());
+    }
+
+    #[tokio::test]
+    async fn should_short_circuit_with_a_canned_response() {
+        let log = Arc::new(Mutex::new(vec![]));
+
+        let client = SpaceTradersClient::with_url("http://127.0.0.1:1", None)
+            .with_retry_policy(RetryPolicy::none())
+            .with_middleware(Recorder {
+                name: "logger",
+                log: log.clone(),
+                ..Recorder::default()
+            })
+            .with_middleware(Recorder {
+                name: "mock",
+                log: log.clone(),
+                canned: Some(ApiResponse::new(
+                    StatusCode::CREATED,
+                    r#"{"data": {"symbol": "BADGER"}}"#,
+                )),
+            })
+            .with_middleware(Recorder {
+                name: "unreached",
+                log: log.clone(),
+                ..Recorder::default()
+            });
+
+        let actual = client
+            .post::<serde_json::Value>("register", StatusCode::CREATED)
+            .await
+            .unwrap();
+
+        assert_eq!("BADGER", actual["data"]["symbol"]);
+
+        let expected = vec![
+            "logger request POST register",
+            "mock request POST register",
+            "logger response 201 Created",
+        ];
+
+        assert_eq!(expected, *log.lock().unwrap());
+    }
+
+    #[tokio::test]
+    async fn later_middlewares_should_see_changes_made_by_earlier_ones() {
+        let seen = Arc::new(Mutex::new(None));
+
+        let client = SpaceTradersClient::with_url("http://localhost", Some(string!("token")))
+            .with_middleware(InjectHeader)
+            .with_middleware(Inspect(seen.clone()));
+
+        client
+            .post_with_body::<_, serde_json::Value>(
+                "register",
+                &serde_json::json!({"symbol": "BADGER"}),
+                StatusCode::OK,
+            )
+            .await
+            .unwrap();
+
+        let request = seen.lock().unwrap().take().unwrap();
+
+        assert_eq!("miners", request.headers["x-fleet"]);
+        assert_eq!("Bearer token", request.headers["authorization"]);
+        assert_eq!(Some(serde_json::json!({"symbol": "BADGER"})), request.body);
+    }
+
+    #[tokio::test]
+    async fn should_pass_encoded_query_to_middlewares() {
+        let seen = Arc::new(Mutex::new(None));
+
+        let client = SpaceTradersClient::with_url("http://localhost", None)
+            .with_middleware(Inspect(seen.clone()));
+
+        client
+            .get::<_, serde_json::Value>(
+                "systems",
+                Some(&[("page", "2"), ("limit", "20")]),
+                StatusCode::OK,
+            )
+            .await
+            .unwrap();
+
+        let request = seen.lock().unwrap().take().unwrap();
+
+        assert_eq!(Some(string!("page=2&limit=20")), request.query);
+    }
+
+    #[tokio::test]
+    async fn should_report_transport_errors() {
+        let log = Arc::new(Mutex::new(vec![]));
+
+        let client = SpaceTradersClient::with_url("http://127.0.0.1:1", None)
+            .with_retry_policy(RetryPolicy::none())
+            .with_middleware(Recorder {
+                name: "logger",
+                log: log.clone(),
+                ..Recorder::default()
+            });
+
+        client
+            .get::<(), serde_json::Value>("my/agent", None, StatusCode::OK)
+            .await
+            .unwrap_err();
+
+        let expected = vec!["logger request GET my/agent", "logger error my/agent"];
+
+        assert_eq!(expected, *log.lock().unwrap());
+    }
+}
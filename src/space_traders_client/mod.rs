@@ -1,16 +1,76 @@
 use std::{fmt, sync::Arc};
 
 use derivative::Derivative;
-use reqwest::{RequestBuilder, StatusCode};
+use reqwest::{
+    header::{HeaderMap, HeaderValue, ACCEPT, AUTHORIZATION},
+    Method, StatusCode,
+};
 use serde::{de::DeserializeOwned, Serialize};
-use serde_derive::Deserialize;
-use serde_repr::Deserialize_repr;
+use serde_derive::{Deserialize, Serialize};
 
-const REAL_SERVER: &'static str = "https://api.spacetraders.io/v2";
+use crate::ship::Cooldown;
 
-#[derive(Debug, Clone, Deserialize_repr, PartialEq)]
-#[repr(u16)]
-pub enum ErrorCode {
+mod rate_limiter;
+pub use rate_limiter::*;
+
+mod retry;
+pub use retry::*;
+
+mod pagination;
+pub use pagination::*;
+
+mod builder;
+pub use builder::*;
+
+mod middleware;
+pub use middleware::*;
+
+mod reset;
+pub use reset::*;
+
+mod token;
+pub use token::*;
+
+mod trace;
+use trace::CallSpan;
+
+const REAL_SERVER: &str = "https://api.spacetraders.io/v2";
+
+macro_rules! error_codes {
+    ($($name:ident = $code:literal,)*) => {
+        #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+        #[serde(from = "u16", into = "u16")]
+        pub enum ErrorCode {
+            $($name,)*
+            Unknown(u16),
+        }
+
+        impl From<u16> for ErrorCode {
+            fn from(code: u16) -> Self {
+                match code {
+                    $($code => ErrorCode::$name,)*
+                    other => ErrorCode::Unknown(other),
+                }
+            }
+        }
+
+        impl ErrorCode {
+            pub const ALL: &[ErrorCode] = &[$(ErrorCode::$name,)*];
+        }
+
+        impl From<ErrorCode> for u16 {
+            fn from(code: ErrorCode) -> Self {
+                match code {
+                    $(ErrorCode::$name => $code,)*
+                    ErrorCode::Unknown(other) => other,
+                }
+            }
+        }
+    };
+}
+
+error_codes! {
+    RateLimitError = 429,
     ResponseSerializationError = 3000,
     UnprocessableInputError = 3001,
     AllErrorHandlersFailedError = 3002,
@@ -120,38 +180,222 @@ pub enum ErrorCode {
 }
 
 #[derive(Debug, PartialEq, Deserialize)]
+#[serde(from = "RawErrorData")]
 pub struct ErrorData {
     pub message: String,
     pub code: ErrorCode,
+    pub request_id: Option<String>,
+    pub data: Option<ErrorDetails>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ErrorDetails {
+    Cooldown(Cooldown),
+    InsufficientFuel {
+        fuel_required: i32,
+        fuel_available: i32,
+    },
+    UnprocessableInput(Vec<ZodIssue>),
+    Other(serde_json::Value),
 }
 
 #[derive(Debug, PartialEq, Deserialize)]
-pub struct Error {
+pub struct ZodIssue {
+    pub code: String,
+    pub message: String,
+    pub path: Vec<serde_json::Value>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawErrorData {
+    message: String,
+    code: ErrorCode,
+    request_id: Option<String>,
+    data: Option<serde_json::Value>,
+}
+
+#[derive(Deserialize)]
+struct CooldownDetails {
+    cooldown: Cooldown,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct InsufficientFuelDetails {
+    fuel_required: i32,
+    fuel_available: i32,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct UnprocessableInputDetails {
+    zod_issues: Vec<ZodIssue>,
+}
+
+impl ErrorDetails {
+    fn from_code(code: &ErrorCode, data: serde_json::Value) -> Self {
+        let details = match code {
+            ErrorCode::CooldownConflictError => {
+                serde_json::from_value::<CooldownDetails>(data.clone())
+                    .map(|details| ErrorDetails::Cooldown(details.cooldown))
+            }
+            ErrorCode::NavigateInsufficientFuelError => serde_json::from_value::<
+                InsufficientFuelDetails,
+            >(data.clone())
+            .map(|details| ErrorDetails::InsufficientFuel {
+                fuel_required: details.fuel_required,
+                fuel_available: details.fuel_available,
+            }),
+            ErrorCode::UnprocessableInputError => {
+                serde_json::from_value::<UnprocessableInputDetails>(data.clone())
+                    .map(|details| ErrorDetails::UnprocessableInput(details.zod_issues))
+            }
+            _ => return ErrorDetails::Other(data),
+        };
+
+        details.unwrap_or(ErrorDetails::Other(data))
+    }
+}
+
+impl From<RawErrorData> for ErrorData {
+    fn from(raw: RawErrorData) -> Self {
+        let data = raw
+            .data
+            .filter(|data| !data.as_object().is_some_and(|object| object.is_empty()))
+            .map(|data| ErrorDetails::from_code(&raw.code, data));
+
+        ErrorData {
+            message: raw.message,
+            code: raw.code,
+            request_id: raw.request_id,
+            data,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+pub struct ErrorResponse {
     pub error: ErrorData,
 }
 
+#[derive(Debug)]
+pub enum Error {
+    Transport(reqwest::Error),
+    Api(ErrorData),
+    Precondition {
+        code: ErrorCode,
+        message: String,
+    },
+    ResetDetected(ResetDetected),
+    UnexpectedStatus {
+        status: StatusCode,
+        body: String,
+    },
+    Deserialization {
+        endpoint: String,
+        path: String,
+        source: serde_json::Error,
+    },
+    InvalidRequest {
+        endpoint: String,
+        message: String,
+    },
+    InvalidToken(String),
+    InvalidConfiguration(String),
+}
+
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "Error {:?}: {}", self.error.code, &self.error.message)
+        match self {
+            Error::Transport(error) => write!(f, "Transport error: {}", error),
+            Error::Api(error) => write!(f, "Error {:?}: {}", error.code, &error.message),
+            Error::Precondition { code, message } => {
+                write!(f, "Error {:?} (not sent): {}", code, message)
+            }
+            Error::ResetDetected(ResetDetected::ResetDateChanged { previous, current }) => {
+                write!(f, "Server reset detected: {} -> {}", previous, current)
+            }
+            Error::ResetDetected(ResetDetected::TokenRejected(error)) => {
+                write!(f, "Server reset detected: {}", &error.message)
+            }
+            Error::UnexpectedStatus { status, body } => {
+                write!(f, "Unexpected status {}: {}", status, body)
+            }
+            Error::Deserialization {
+                endpoint,
+                path,
+                source,
+            } => write!(
+                f,
+                "Failed to deserialize response from {} at {}: {}",
+                endpoint, path, source
+            ),
+            Error::InvalidRequest { endpoint, message } => {
+                write!(f, "Invalid request to {}: {}", endpoint, message)
+            }
+            Error::InvalidToken(message) => write!(f, "Invalid token: {}", message),
+            Error::InvalidConfiguration(message) => {
+                write!(f, "Invalid configuration: {}", message)
+            }
+        }
     }
 }
 
-#[derive(Debug, Derivative, Clone)]
-#[derivative(PartialEq)]
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Transport(error) => Some(error),
+            Error::Deserialization { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+impl From<reqwest::Error> for Error {
+    fn from(error: reqwest::Error) -> Self {
+        Error::Transport(error)
+    }
+}
+
+impl Error {
+    pub fn code(&self) -> Option<&ErrorCode> {
+        match self {
+            Error::Api(error) | Error::ResetDetected(ResetDetected::TokenRejected(error)) => {
+                Some(&error.code)
+            }
+            Error::Precondition { code, .. } => Some(code),
+            _ => None,
+        }
+    }
+
+    pub fn details(&self) -> Option<&ErrorDetails> {
+        match self {
+            Error::Api(error) | Error::ResetDetected(ResetDetected::TokenRejected(error)) => {
+                error.data.as_ref()
+            }
+            _ => None,
+        }
+    }
+}
+
+#[derive(Derivative, Clone)]
+#[derivative(Debug, PartialEq)]
 pub struct SpaceTradersClient {
     #[derivative(PartialEq = "ignore")]
     client: Arc<reqwest::Client>,
+    #[derivative(PartialEq = "ignore")]
+    rate_limiter: Arc<RateLimiter>,
+    #[derivative(Debug = "ignore", PartialEq = "ignore")]
+    middlewares: Vec<Arc<dyn Middleware>>,
+    retry_policy: RetryPolicy,
     url: String,
     token: Option<String>,
 }
 
 impl SpaceTradersClient {
     pub fn new(token: Option<String>) -> Self {
-        SpaceTradersClient {
-            client: Arc::new(reqwest::Client::new()),
-            url: REAL_SERVER.to_string(),
-            token,
-        }
+        SpaceTradersClient::with_url(REAL_SERVER, token)
     }
 
     pub fn clone_with_token(client: &SpaceTradersClient, new_token: &str) -> Self {
@@ -164,64 +408,234 @@ impl SpaceTradersClient {
     pub fn with_url(url: &str, token: Option<String>) -> Self {
         SpaceTradersClient {
             client: Arc::new(reqwest::Client::new()),
+            rate_limiter: Arc::new(RateLimiter::new(RateLimit::default())),
+            middlewares: vec![],
+            retry_policy: RetryPolicy::default(),
             url: url.to_string(),
             token,
         }
     }
 
+    pub fn builder() -> SpaceTradersClientBuilder {
+        SpaceTradersClientBuilder::new()
+    }
+
+    pub fn with_rate_limit(self, rate_limit: RateLimit) -> Result<Self, Error> {
+        rate_limit.validate()?;
+
+        Ok(SpaceTradersClient {
+            rate_limiter: Arc::new(RateLimiter::new(rate_limit)),
+            ..self
+        })
+    }
+
+    pub fn with_retry_policy(self, retry_policy: RetryPolicy) -> Self {
+        SpaceTradersClient {
+            retry_policy,
+            ..self
+        }
+    }
+
+    pub fn with_middleware(mut self, middleware: impl Middleware + 'static) -> Self {
+        self.middlewares.push(Arc::new(middleware));
+        self
+    }
+
     pub fn get_token(&self) -> Option<&str> {
         self.token.as_deref()
     }
 
+    pub fn decode_token(&self) -> Option<Result<Token, Error>> {
+        self.token.as_deref().map(Token::parse)
+    }
+
+    fn new_request(&self, method: Method, endpoint: &str) -> Result<ApiRequest, Error> {
+        let mut headers = HeaderMap::new();
+        headers.insert(ACCEPT, HeaderValue::from_static("application/json"));
+
+        if let Some(token) = &self.token {
+            let mut value = HeaderValue::from_str(&format!("Bearer {}", token)).map_err(|e| {
+                Error::InvalidRequest {
+                    endpoint: endpoint.to_string(),
+                    message: e.to_string(),
+                }
+            })?;
+            value.set_sensitive(true);
+            headers.insert(AUTHORIZATION, value);
+        }
+
+        Ok(ApiRequest {
+            method,
+            endpoint: endpoint.to_string(),
+            query: None,
+            body: None,
+            headers,
+        })
+    }
+
     async fn send_and_handle_request_response<R: DeserializeOwned>(
-        request: RequestBuilder,
+        &self,
+        request: ApiRequest,
         success_status: StatusCode,
     ) -> Result<R, Error> {
-        let result = request.send().await;
-
-        match result {
-            Ok(response) => {
-                if response.status() == success_status {
-                    match response.json::<R>().await {
-                        Ok(res) => Ok(res),
-                        Err(_e) => {
-                            println!("{}", _e);
-                            todo!()
-                        }
+        let span = CallSpan::new(&request);
+
+        let result = span
+            .instrument(self.send_with_retries(&request))
+            .await
+            .and_then(|response| {
+                span.record_response(&response);
+                Self::handle_response(&request.endpoint, response, success_status)
+            });
+
+        span.record_result(&result);
+        result
+    }
+
+    async fn send_with_retries(&self, request: &ApiRequest) -> Result<ApiResponse, Error> {
+        let mut attempt = 1;
+
+        loop {
+            let mut attempt_request = request.clone();
+            let (canned, seen) = self.run_request_middlewares(&mut attempt_request);
+            let short_circuited = canned.is_some();
+
+            let result = match canned {
+                Some(response) => Ok(response),
+                None => self.execute(&attempt_request).await,
+            };
+
+            let can_retry = !short_circuited && attempt < self.retry_policy.max_attempts;
+
+            match result {
+                Ok(mut response) => {
+                    for middleware in self.middlewares[..seen].iter().rev() {
+                        middleware.on_response(&attempt_request, &mut response);
+                    }
+
+                    if !(can_retry
+                        && self
+                            .retry_policy
+                            .should_retry_status(&request.method, response.status))
+                    {
+                        return Ok(response);
                     }
-                } else {
-                    match response.json::<Error>().await {
-                        Ok(err) => Err(err),
-                        Err(_e) => todo!(),
+                }
+                Err((error, elapsed)) => {
+                    for middleware in self.middlewares[..seen].iter().rev() {
+                        middleware.on_error(&attempt_request, &error, elapsed);
+                    }
+
+                    if !(can_retry
+                        && self
+                            .retry_policy
+                            .should_retry_error(&request.method, &error))
+                    {
+                        return Err(error.into());
                     }
                 }
             }
-            Err(_error) => {
-                todo!()
+
+            tokio::time::sleep(self.retry_policy.backoff(attempt)).await;
+            attempt += 1;
+            trace::retry(attempt);
+        }
+    }
+
+    fn run_request_middlewares(&self, request: &mut ApiRequest) -> (Option<ApiResponse>, usize) {
+        for (index, middleware) in self.middlewares.iter().enumerate() {
+            if let Some(response) = middleware.on_request(request) {
+                return (Some(response), index);
+            }
+        }
+
+        (None, self.middlewares.len())
+    }
+
+    async fn execute(
+        &self,
+        request: &ApiRequest,
+    ) -> Result<ApiResponse, (reqwest::Error, std::time::Duration)> {
+        let mut url = format!("{}/{}", self.url, request.endpoint);
+        if let Some(query) = &request.query {
+            url = format!("{}?{}", url, query);
+        }
+
+        let mut builder = self
+            .client
+            .request(request.method.clone(), url)
+            .headers(request.headers.clone());
+
+        if let Some(body) = &request.body {
+            builder = builder.json(body);
+        }
+
+        self.rate_limiter.acquire().await;
+
+        let start = tokio::time::Instant::now();
+        let response = builder.send().await.map_err(|e| (e, start.elapsed()))?;
+        let status = response.status();
+        let headers = response.headers().clone();
+
+        self.rate_limiter
+            .update_from_response(status, &headers)
+            .await;
+
+        let body = response.text().await.map_err(|e| (e, start.elapsed()))?;
+
+        Ok(ApiResponse {
+            status,
+            headers,
+            body,
+            elapsed: start.elapsed(),
+        })
+    }
+
+    fn handle_response<R: DeserializeOwned>(
+        endpoint: &str,
+        response: ApiResponse,
+        success_status: StatusCode,
+    ) -> Result<R, Error> {
+        let ApiResponse { status, body, .. } = response;
+
+        if status == success_status {
+            let deserializer = &mut serde_json::Deserializer::from_str(&body);
+            serde_path_to_error::deserialize(deserializer).map_err(|e| Error::Deserialization {
+                endpoint: endpoint.to_string(),
+                path: e.path().to_string(),
+                source: e.into_inner(),
+            })
+        } else {
+            match serde_json::from_str::<ErrorResponse>(&body) {
+                Ok(response) => match ResetDetected::from_api_error(response.error) {
+                    Ok(reset) => Err(Error::ResetDetected(reset)),
+                    Err(error) => Err(Error::Api(error)),
+                },
+                Err(_) => Err(Error::UnexpectedStatus { status, body }),
             }
         }
     }
 
-    async fn internal_post<T: Serialize + ?Sized, R: DeserializeOwned>(
+    async fn internal_send_with_body<T: Serialize + ?Sized, R: DeserializeOwned>(
         &self,
+        method: Method,
         endpoint: &str,
         body: Option<&T>,
         success_status: StatusCode,
     ) -> Result<R, Error> {
-        let mut request = self
-            .client
-            .post(&format!("{}/{}", self.url, endpoint))
-            .header("Accept", "application/json");
+        let mut request = self.new_request(method, endpoint)?;
 
         if let Some(body) = body {
-            request = request.json(body);
+            request.body = Some(
+                serde_json::to_value(body).map_err(|e| Error::InvalidRequest {
+                    endpoint: endpoint.to_string(),
+                    message: e.to_string(),
+                })?,
+            );
         };
 
-        if let Some(token) = &self.token {
-            request = request.bearer_auth(token);
-        }
-
-        SpaceTradersClient::send_and_handle_request_response(request, success_status).await
+        self.send_and_handle_request_response(request, success_status)
+            .await
     }
 
     // TODO: test?
@@ -231,20 +645,19 @@ impl SpaceTradersClient {
         query_params: Option<&T>,
         success_status: StatusCode,
     ) -> Result<R, Error> {
-        let mut request = self
-            .client
-            .get(&format!("{}/{}", self.url, endpoint))
-            .header("Accept", "application/json");
-
-        if let Some(token) = &self.token {
-            request = request.bearer_auth(token);
-        }
+        let mut request = self.new_request(Method::GET, endpoint)?;
 
         if let Some(query_params) = query_params {
-            request = request.query(query_params);
+            let query =
+                serde_urlencoded::to_string(query_params).map_err(|e| Error::InvalidRequest {
+                    endpoint: endpoint.to_string(),
+                    message: e.to_string(),
+                })?;
+            request.query = Some(query).filter(|query| !query.is_empty());
         }
 
-        SpaceTradersClient::send_and_handle_request_response(request, success_status).await
+        self.send_and_handle_request_response(request, success_status)
+            .await
     }
 
     pub async fn post<R: DeserializeOwned>(
@@ -252,8 +665,13 @@ impl SpaceTradersClient {
         endpoint: &str,
         success_status: StatusCode,
     ) -> Result<R, Error> {
-        self.internal_post::<serde_json::Value, R>(endpoint, None, success_status)
-            .await
+        self.internal_send_with_body::<serde_json::Value, R>(
+            Method::POST,
+            endpoint,
+            None,
+            success_status,
+        )
+        .await
     }
 
     pub async fn post_with_body<T: Serialize + ?Sized, R: DeserializeOwned>(
@@ -262,17 +680,33 @@ impl SpaceTradersClient {
         body: &T,
         success_status: StatusCode,
     ) -> Result<R, Error> {
-        self.internal_post(endpoint, Some(body), success_status)
+        self.internal_send_with_body(Method::POST, endpoint, Some(body), success_status)
+            .await
+    }
+
+    pub async fn patch_with_body<T: Serialize + ?Sized, R: DeserializeOwned>(
+        &self,
+        endpoint: &str,
+        body: &T,
+        success_status: StatusCode,
+    ) -> Result<R, Error> {
+        self.internal_send_with_body(Method::PATCH, endpoint, Some(body), success_status)
             .await
     }
 }
 
 #[cfg(test)]
 pub mod tests {
+    use std::sync::Arc;
+
     use mock_server::{MockServerBuilder, RequestMethod};
 
     use crate::{
-        space_traders_client::{Error, ErrorCode, ErrorData, SpaceTradersClient},
+        ship::Cooldown,
+        space_traders_client::{
+            Error, ErrorCode, ErrorData, ErrorDetails, ErrorResponse, RetryPolicy,
+            SpaceTradersClient, ZodIssue,
+        },
         string,
     };
 
@@ -290,10 +724,226 @@ pub mod tests {
 
         let client = SpaceTradersClient::with_url(&mock_server.url(), None);
 
-        let _response: Error = client
+        let actual: Error = client
+            .post::<serde_json::Value>("register", reqwest::StatusCode::CREATED)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(actual, Error::Api(_)));
+        assert_eq!(Some(&ErrorCode::MissingTokenRequestError), actual.code());
+    }
+
+    #[tokio::test]
+    async fn should_return_unexpected_status_error_with_raw_body_if_error_is_not_json() {
+        let mock_server = MockServerBuilder::mock_once::<serde_json::Value>(
+            RequestMethod::Post,
+            "register",
+            502,
+            None,
+            None,
+        )
+        .await;
+
+        let client = SpaceTradersClient::with_url(&mock_server.url(), None);
+
+        let actual = client
+            .post::<serde_json::Value>("register", reqwest::StatusCode::CREATED)
+            .await
+            .unwrap_err();
+
+        match actual {
+            Error::UnexpectedStatus { status, body } => {
+                assert_eq!(reqwest::StatusCode::BAD_GATEWAY, status);
+                assert_eq!("Bad Gateway\n", body);
+            }
+            other => panic!("Expected UnexpectedStatus, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn should_return_deserialization_error_with_endpoint_and_path() {
+        #[derive(Debug, serde_derive::Deserialize)]
+        struct UnexpectedAgent {
+            #[allow(dead_code)]
+            credits: String,
+        }
+
+        #[derive(Debug, serde_derive::Deserialize)]
+        struct UnexpectedAgentResponse {
+            #[allow(dead_code)]
+            data: UnexpectedAgent,
+        }
+
+        let mock_server =
+            MockServerBuilder::mock_once(RequestMethod::Get, "my/agent", 200, None, None::<&()>)
+                .await;
+
+        let client = SpaceTradersClient::with_url(&mock_server.url(), None);
+
+        let actual = client
+            .get::<(), UnexpectedAgentResponse>("my/agent", None, reqwest::StatusCode::OK)
+            .await
+            .unwrap_err();
+
+        match actual {
+            Error::Deserialization { endpoint, path, .. } => {
+                assert_eq!("my/agent", endpoint);
+                assert_eq!("data.credits", path);
+            }
+            other => panic!("Expected Deserialization, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn should_wait_for_retry_after_when_rate_limited() {
+        let mock_server = MockServerBuilder::new()
+            .await
+            .mock_response_with_headers(
+                RequestMethod::Get,
+                "my/agent",
+                429,
+                None,
+                None::<&()>,
+                &[
+                    ("retry-after", "1"),
+                    ("x-ratelimit-limit-per-second", "2"),
+                    ("x-ratelimit-limit-burst", "30"),
+                    ("x-ratelimit-remaining", "0"),
+                ],
+            )
+            .await
+            .mock_response(
+                RequestMethod::Get,
+                "my/contracts",
+                200,
+                None,
+                Some(&[("page", 1), ("limit", 20)]),
+            )
+            .await
+            .build();
+
+        let client = SpaceTradersClient::with_url(&mock_server.url(), None)
+            .with_retry_policy(RetryPolicy::none());
+
+        let rate_limited = client
+            .get::<(), serde_json::Value>("my/agent", None, reqwest::StatusCode::OK)
+            .await
+            .unwrap_err();
+
+        assert_eq!(Some(&ErrorCode::RateLimitError), rate_limited.code());
+
+        let start = std::time::Instant::now();
+
+        client
+            .get::<_, serde_json::Value>(
+                "my/contracts",
+                Some(&[("page", 1), ("limit", 20)]),
+                reqwest::StatusCode::OK,
+            )
+            .await
+            .unwrap();
+
+        assert!(start.elapsed() >= std::time::Duration::from_millis(900));
+    }
+
+    #[tokio::test]
+    async fn clients_cloned_with_a_new_token_should_share_the_rate_limiter() {
+        let client = SpaceTradersClient::with_url("http://localhost", None);
+
+        let cloned = SpaceTradersClient::clone_with_token(&client, "token");
+
+        assert!(Arc::ptr_eq(&client.rate_limiter, &cloned.rate_limiter));
+    }
+
+    fn fast_retries() -> RetryPolicy {
+        RetryPolicy {
+            initial_backoff: std::time::Duration::from_millis(1),
+            jitter: false,
+            ..RetryPolicy::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn get_should_be_retried_after_a_transient_server_error() {
+        let mock_server = MockServerBuilder::new()
+            .await
+            .mock_response(RequestMethod::Get, "my/agent", 502, None, None::<&()>)
+            .await
+            .mock_response(RequestMethod::Get, "my/agent", 200, None, None::<&()>)
+            .await
+            .build();
+
+        let client = SpaceTradersClient::with_url(&mock_server.url(), None)
+            .with_retry_policy(fast_retries());
+
+        let actual = client
+            .get::<(), serde_json::Value>("my/agent", None, reqwest::StatusCode::OK)
+            .await
+            .unwrap();
+
+        assert_eq!("BADGER", actual["data"]["symbol"]);
+    }
+
+    #[tokio::test]
+    async fn post_should_not_be_retried_after_a_server_error() {
+        let mock_server = MockServerBuilder::new()
+            .await
+            .mock_response::<serde_json::Value>(RequestMethod::Post, "register", 502, None, None)
+            .await
+            .mock_response::<serde_json::Value>(RequestMethod::Post, "register", 201, None, None)
+            .await
+            .build();
+
+        let client = SpaceTradersClient::with_url(&mock_server.url(), None)
+            .with_retry_policy(fast_retries());
+
+        let actual = client
             .post::<serde_json::Value>("register", reqwest::StatusCode::CREATED)
             .await
             .unwrap_err();
+
+        assert!(matches!(actual, Error::UnexpectedStatus { .. }));
+    }
+
+    #[tokio::test]
+    async fn should_give_up_after_max_attempts() {
+        let mock_server = MockServerBuilder::new()
+            .await
+            .mock_response(RequestMethod::Get, "my/agent", 502, None, None::<&()>)
+            .await
+            .build();
+
+        let client =
+            SpaceTradersClient::with_url(&mock_server.url(), None).with_retry_policy(RetryPolicy {
+                max_attempts: 2,
+                ..fast_retries()
+            });
+
+        let actual = client
+            .get::<(), serde_json::Value>("my/agent", None, reqwest::StatusCode::OK)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(
+            actual,
+            Error::UnexpectedStatus {
+                status: reqwest::StatusCode::BAD_GATEWAY,
+                ..
+            }
+        ));
+    }
+
+    #[tokio::test]
+    async fn should_return_transport_error_if_server_is_unreachable() {
+        let client = SpaceTradersClient::with_url("http://127.0.0.1:1", None)
+            .with_retry_policy(RetryPolicy::none());
+
+        let actual = client
+            .get::<(), serde_json::Value>("my/agent", None, reqwest::StatusCode::OK)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(actual, Error::Transport(_)));
     }
 
     #[test]
@@ -308,13 +958,15 @@ pub mod tests {
             }
         }"#;
 
-        let actual: Error = serde_json::from_str(json_str).unwrap();
+        let actual: ErrorResponse = serde_json::from_str(json_str).unwrap();
 
         let expected =
-        Error {
+        ErrorResponse {
             error: ErrorData {
                 message: string!("Missing Bearer token in the request. Did you confirm sending the 'Bearer {token}' as the authorization header?"),
                 code: ErrorCode::MissingTokenRequestError,
+                request_id: Some(string!("0197408d-a4ad-7351-acef-c3c42a4ffd06")),
+                data: None,
             }
         };
 
@@ -340,12 +992,19 @@ pub mod tests {
             }
         }"#;
 
-        let actual: Error = serde_json::from_str(json_str).unwrap();
+        let actual: ErrorResponse = serde_json::from_str(json_str).unwrap();
 
-        let expected = Error {
+        let expected = ErrorResponse {
             error: ErrorData {
                 message: string!("Ship action is still on cooldown for 47 second(s)."),
                 code: ErrorCode::CooldownConflictError,
+                request_id: Some(string!("01972cc8-b922-73f7-b929-49e43546ef15")),
+                data: Some(ErrorDetails::Cooldown(Cooldown {
+                    ship_symbol: string!("SHOOTTEST-3"),
+                    total_seconds: 70,
+                    remaining_seconds: 47,
+                    expiration: Some(string!("2025-06-01T18:38:08.614Z")),
+                })),
             },
         };
 
@@ -363,12 +1022,14 @@ pub mod tests {
             }
         }"#;
 
-        let actual: Error = serde_json::from_str(json_str).unwrap();
+        let actual: ErrorResponse = serde_json::from_str(json_str).unwrap();
 
-        let expected = Error {
+        let expected = ErrorResponse {
             error: ErrorData {
                 message: string!("You specified a 'Content-Type' header of 'application/json', but the request body is an empty string (which can't be parsed as valid JSON). Send an empty object (e.g. {}) instead."),
                 code: ErrorCode::UnprocessableInputError,
+                request_id: Some(string!("01974086-5ec7-777f-b735-1c0fb6e7442d")),
+                data: None,
             },
         };
 
@@ -409,15 +1070,99 @@ pub mod tests {
             }
         }"#;
 
-        let actual: Error = serde_json::from_str(json_str).unwrap();
+        let actual: ErrorResponse = serde_json::from_str(json_str).unwrap();
 
-        let expected = Error {
+        let expected = ErrorResponse {
             error: ErrorData {
                 message: string!("The request could not be processed due to an invalid payload or application state."),
                 code: ErrorCode::UnprocessableInputError,
+                request_id: Some(string!("01974087-b835-714d-a5fa-08ead571d78c")),
+                data: Some(ErrorDetails::UnprocessableInput(vec![ZodIssue {
+                    code: string!("invalid_type"),
+                    message: string!("Expected object, received string"),
+                    path: vec![],
+                }])),
             },
         };
 
         assert_eq!(expected, actual);
     }
+
+    #[test]
+    fn insufficient_fuel_error_should_be_deserializable() {
+        let json_str = r#"
+        {
+            "error": {
+                "code": 4203,
+                "message": "Navigate request failed. Ship SNAKE-1 requires 63 more fuel for navigation.",
+                "data": {
+                    "shipSymbol": "SNAKE-1",
+                    "fuelRequired": 63,
+                    "fuelAvailable": 0
+                },
+                "requestId": "01974090-1f2b-7c3d-9e4f-5a6b7c8d9e0f"
+            }
+        }"#;
+
+        let actual: ErrorResponse = serde_json::from_str(json_str).unwrap();
+
+        let expected = ErrorResponse {
+            error: ErrorData {
+                message: string!(
+                    "Navigate request failed. Ship SNAKE-1 requires 63 more fuel for navigation."
+                ),
+                code: ErrorCode::NavigateInsufficientFuelError,
+                request_id: Some(string!("01974090-1f2b-7c3d-9e4f-5a6b7c8d9e0f")),
+                data: Some(ErrorDetails::InsufficientFuel {
+                    fuel_required: 63,
+                    fuel_available: 0,
+                }),
+            },
+        };
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn error_data_that_does_not_match_its_code_should_be_kept_as_raw_json() {
+        let json_str = r#"
+        {
+            "error": {
+                "code": 4000,
+                "message": "Ship action is still on cooldown.",
+                "data": {
+                    "unexpected": true
+                }
+            }
+        }"#;
+
+        let actual: ErrorResponse = serde_json::from_str(json_str).unwrap();
+
+        let expected = ErrorResponse {
+            error: ErrorData {
+                message: string!("Ship action is still on cooldown."),
+                code: ErrorCode::CooldownConflictError,
+                request_id: None,
+                data: Some(ErrorDetails::Other(serde_json::json!({"unexpected": true}))),
+            },
+        };
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn unknown_error_code_should_round_trip() {
+        let actual: ErrorCode = serde_json::from_str("4999").unwrap();
+
+        assert_eq!(ErrorCode::Unknown(4999), actual);
+        assert_eq!("4999", serde_json::to_string(&actual).unwrap());
+    }
+
+    #[test]
+    fn known_error_code_should_round_trip() {
+        let actual: ErrorCode = serde_json::from_str("4203").unwrap();
+
+        assert_eq!(ErrorCode::NavigateInsufficientFuelError, actual);
+        assert_eq!("4203", serde_json::to_string(&actual).unwrap());
+    }
 }
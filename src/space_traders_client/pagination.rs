@@ -0,0 +1,140 @@
+use std::sync::Arc;
+
+use futures::{stream, Stream, TryStreamExt};
+use reqwest::StatusCode;
+use serde::{de::DeserializeOwned, Serialize};
+use serde_derive::{Deserialize, Serialize};
+
+use super::{Error, SpaceTradersClient};
+
+pub const MAX_PAGE_LIMIT: u32 = 20;
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Meta {
+    pub total: u32,
+    pub page: u32,
+    pub limit: u32,
+}
+
+impl Meta {
+    fn has_next_page(&self, fetched: usize) -> bool {
+        fetched as u32 == self.limit && self.page.saturating_mul(self.limit) < self.total
+    }
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+pub struct Page<T> {
+    pub data: Vec<T>,
+    pub meta: Meta,
+}
+
+impl<T> Page<T> {
+    pub fn map<U>(self, f: impl FnMut(T) -> U) -> Page<U> {
+        Page {
+            data: self.data.into_iter().map(f).collect(),
+            meta: self.meta,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct PageParams<'a, Q: Serialize + ?Sized> {
+    #[serde(flatten)]
+    query: Option<&'a Q>,
+    page: u32,
+    limit: u32,
+}
+
+impl SpaceTradersClient {
+    pub async fn get_page<Q: Serialize + ?Sized, T: DeserializeOwned>(
+        &self,
+        endpoint: &str,
+        query_params: Option<&Q>,
+        page: u32,
+        limit: u32,
+    ) -> Result<Page<T>, Error> {
+        let params = PageParams {
+            query: query_params,
+            page,
+            limit,
+        };
+
+        self.get(endpoint, Some(&params), StatusCode::OK).await
+    }
+
+    pub fn paginate<'a, Q, T>(
+        &'a self,
+        endpoint: String,
+        query_params: Option<Q>,
+        limit: u32,
+    ) -> impl Stream<Item = Result<T, Error>> + 'a
+    where
+        Q: Serialize + 'a,
+        T: DeserializeOwned + 'a,
+    {
+        let request = Arc::new((endpoint, query_params));
+
+        stream::try_unfold(Some(1), move |page| {
+            let request = request.clone();
+
+            async move {
+                let Some(page) = page else {
+                    return Ok::<_, Error>(None);
+                };
+
+                let (endpoint, query_params) = request.as_ref();
+                let response: Page<T> = self
+                    .get_page(endpoint, query_params.as_ref(), page, limit)
+                    .await?;
+                let next_page = response
+                    .meta
+                    .has_next_page(response.data.len())
+                    .then_some(page + 1);
+
+                Ok(Some((
+                    stream::iter(response.data.into_iter().map(Ok)),
+                    next_page,
+                )))
+            }
+        })
+        .try_flatten()
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    #[test]
+    fn should_have_next_page_while_full_pages_are_below_the_total() {
+        let meta = Meta {
+            total: 45,
+            page: 2,
+            limit: 20,
+        };
+
+        assert!(meta.has_next_page(20));
+    }
+
+    #[test]
+    fn should_not_have_next_page_on_the_last_page() {
+        let meta = Meta {
+            total: 45,
+            page: 3,
+            limit: 20,
+        };
+
+        assert!(!meta.has_next_page(5));
+    }
+
+    #[test]
+    fn should_not_have_next_page_when_a_page_comes_back_short() {
+        let meta = Meta {
+            total: 101,
+            page: 1,
+            limit: 10,
+        };
+
+        assert!(!meta.has_next_page(5));
+    }
+}
@@ -0,0 +1,374 @@
+use std::time::Duration;
+
+use reqwest::{header::HeaderMap, StatusCode};
+use tokio::{sync::Mutex, time::Instant};
+
+use super::Error;
+
+const RETRY_AFTER: &str = "retry-after";
+const LIMIT_PER_SECOND: &str = "x-ratelimit-limit-per-second";
+const LIMIT_BURST: &str = "x-ratelimit-limit-burst";
+const REMAINING: &str = "x-ratelimit-remaining";
+const RECHECK_INTERVAL: Duration = Duration::from_secs(1);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RateLimit {
+    pub per_second: f64,
+    pub burst: u32,
+    pub burst_duration: Duration,
+}
+
+impl Default for RateLimit {
+    fn default() -> Self {
+        RateLimit {
+            per_second: 2.0,
+            burst: 30,
+            burst_duration: Duration::from_secs(60),
+        }
+    }
+}
+
+impl RateLimit {
+    // Without a positive sustained rate and burst window the buckets would never refill.
+    pub fn validate(&self) -> Result<(), Error> {
+        if !(self.per_second.is_finite() && self.per_second > 0.0) {
+            return Err(Error::InvalidConfiguration(format!(
+                "Rate limit per second must be positive, got {}",
+                self.per_second
+            )));
+        }
+
+        if self.burst_duration.is_zero() {
+            return Err(Error::InvalidConfiguration(String::from(
+                "Rate limit burst duration must not be zero",
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+struct Buckets {
+    limit: RateLimit,
+    sustained: f64,
+    burst: f64,
+    last_refill: Instant,
+    blocked_until: Option<Instant>,
+}
+
+impl Buckets {
+    fn refill(&mut self, now: Instant) {
+        let elapsed = now.duration_since(self.last_refill).as_secs_f64();
+        self.last_refill = now;
+
+        self.sustained =
+            (self.sustained + elapsed * self.limit.per_second).min(self.limit.per_second);
+        self.burst = (self.burst + elapsed * self.burst_refill_rate()).min(self.limit.burst as f64);
+    }
+
+    fn burst_refill_rate(&self) -> f64 {
+        self.limit.burst as f64 / self.limit.burst_duration.as_secs_f64()
+    }
+
+    fn try_take(&mut self) -> Option<Duration> {
+        if self.sustained >= 1.0 {
+            self.sustained -= 1.0;
+            return None;
+        }
+
+        if self.burst >= 1.0 {
+            self.burst -= 1.0;
+            return None;
+        }
+
+        let until_sustained = (1.0 - self.sustained) / self.limit.per_second;
+        let until_burst = (1.0 - self.burst) / self.burst_refill_rate();
+
+        Some(
+            Duration::try_from_secs_f64(until_sustained.min(until_burst))
+                .unwrap_or(RECHECK_INTERVAL),
+        )
+    }
+}
+
+#[derive(Debug)]
+pub struct RateLimiter {
+    queue: Mutex<()>,
+    buckets: Mutex<Buckets>,
+}
+
+impl RateLimiter {
+    pub fn new(limit: RateLimit) -> Self {
+        RateLimiter {
+            queue: Mutex::new(()),
+            buckets: Mutex::new(Buckets {
+                sustained: limit.per_second,
+                burst: limit.burst as f64,
+                limit,
+                last_refill: Instant::now(),
+                blocked_until: None,
+            }),
+        }
+    }
+
+    // Waiters queue on `queue` so they are served in order, while `buckets` is only locked to
+    // reserve a slot and never across a sleep, so responses can still update the limits.
+    pub async fn acquire(&self) {
+        let _turn = self.queue.lock().await;
+
+        loop {
+            let wait = {
+                let mut buckets = self.buckets.lock().await;
+                let now = Instant::now();
+
+                match buckets.blocked_until {
+                    Some(blocked_until) if blocked_until > now => Some(blocked_until - now),
+                    _ => {
+                        buckets.blocked_until = None;
+                        buckets.refill(now);
+                        buckets.try_take()
+                    }
+                }
+            };
+
+            match wait {
+                None => return,
+                Some(wait) => tokio::time::sleep(wait).await,
+            }
+        }
+    }
+
+    pub async fn update_from_response(&self, status: StatusCode, headers: &HeaderMap) {
+        let mut buckets = self.buckets.lock().await;
+
+        if let Some(per_second) = header_value::<f64>(headers, LIMIT_PER_SECOND) {
+            if per_second.is_finite() && per_second > 0.0 {
+                buckets.limit.per_second = per_second;
+            }
+        }
+
+        if let Some(burst) = header_value::<u32>(headers, LIMIT_BURST) {
+            if burst > 0 {
+                buckets.limit.burst = burst;
+            }
+        }
+
+        if let Some(remaining) = header_value::<f64>(headers, REMAINING) {
+            buckets.burst = buckets.burst.min(remaining.max(0.0));
+        }
+
+        if status == StatusCode::TOO_MANY_REQUESTS {
+            buckets.sustained = 0.0;
+            buckets.burst = 0.0;
+
+            // A retry-after that isn't a usable duration is ignored, the emptied buckets still
+            // slow the next requests down.
+            if let Some(blocked_until) = header_value::<f64>(headers, RETRY_AFTER)
+                .and_then(|retry_after| Duration::try_from_secs_f64(retry_after.max(0.0)).ok())
+                .and_then(|retry_after| Instant::now().checked_add(retry_after))
+            {
+                buckets.blocked_until = Some(blocked_until);
+            }
+        }
+    }
+
+    pub async fn limit(&self) -> RateLimit {
+        self.buckets.lock().await.limit.clone()
+    }
+}
+
+fn header_value<T: std::str::FromStr>(headers: &HeaderMap, name: &str) -> Option<T> {
+    headers.get(name)?.to_str().ok()?.trim().parse().ok()
+}
+
+#[cfg(test)]
+pub mod tests {
+    use std::sync::Arc;
+
+    use reqwest::header::{HeaderMap, HeaderValue};
+
+    use super::*;
+
+    fn headers(values: &[(&'static str, &'static str)]) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        for (name, value) in values {
+            headers.insert(*name, HeaderValue::from_static(value));
+        }
+        headers
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn should_allow_sustained_and_burst_requests_without_waiting() {
+        let limiter = RateLimiter::new(RateLimit {
+            per_second: 2.0,
+            burst: 3,
+            burst_duration: Duration::from_secs(60),
+        });
+
+        let start = Instant::now();
+        for _ in 0..5 {
+            limiter.acquire().await;
+        }
+
+        assert_eq!(Duration::ZERO, start.elapsed());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn should_wait_for_the_sustained_rate_once_tokens_are_spent() {
+        let limiter = RateLimiter::new(RateLimit {
+            per_second: 2.0,
+            burst: 0,
+            burst_duration: Duration::from_secs(60),
+        });
+
+        let start = Instant::now();
+        for _ in 0..4 {
+            limiter.acquire().await;
+        }
+
+        assert_eq!(Duration::from_secs(1), start.elapsed());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn should_serve_waiting_callers_in_order() {
+        let limiter = Arc::new(RateLimiter::new(RateLimit {
+            per_second: 1.0,
+            burst: 0,
+            burst_duration: Duration::from_secs(60),
+        }));
+        let order = Arc::new(Mutex::new(vec![]));
+
+        limiter.acquire().await;
+
+        let mut handles = vec![];
+        for caller in 0..3 {
+            let limiter = limiter.clone();
+            let order = order.clone();
+            handles.push(tokio::spawn(async move {
+                limiter.acquire().await;
+                order.lock().await.push(caller);
+            }));
+            tokio::task::yield_now().await;
+        }
+
+        for handle in handles {
+            handle.await.unwrap();
+        }
+
+        assert_eq!(vec![0, 1, 2], *order.lock().await);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn should_block_until_retry_after_on_too_many_requests() {
+        let limiter = RateLimiter::new(RateLimit::default());
+
+        limiter
+            .update_from_response(
+                StatusCode::TOO_MANY_REQUESTS,
+                &headers(&[("retry-after", "3")]),
+            )
+            .await;
+
+        let start = Instant::now();
+        limiter.acquire().await;
+
+        assert_eq!(Duration::from_secs(3), start.elapsed());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn should_accept_updates_while_a_caller_is_waiting() {
+        let limiter = Arc::new(RateLimiter::new(RateLimit {
+            per_second: 1.0,
+            burst: 0,
+            burst_duration: Duration::from_secs(60),
+        }));
+
+        limiter.acquire().await;
+
+        let waiting = {
+            let limiter = limiter.clone();
+            tokio::spawn(async move { limiter.acquire().await })
+        };
+        tokio::task::yield_now().await;
+
+        let start = Instant::now();
+        limiter
+            .update_from_response(StatusCode::OK, &headers(&[("x-ratelimit-remaining", "0")]))
+            .await;
+
+        assert_eq!(Duration::ZERO, start.elapsed());
+        waiting.await.unwrap();
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn should_ignore_unusable_retry_after_values() {
+        for retry_after in ["inf", "1e20", "NaN", "-5"] {
+            let limiter = RateLimiter::new(RateLimit {
+                per_second: 1.0,
+                burst: 0,
+                burst_duration: Duration::from_secs(60),
+            });
+            let mut values = HeaderMap::new();
+            values.insert("retry-after", HeaderValue::from_str(retry_after).unwrap());
+
+            limiter
+                .update_from_response(StatusCode::TOO_MANY_REQUESTS, &values)
+                .await;
+
+            let start = Instant::now();
+            limiter.acquire().await;
+
+            assert!(start.elapsed() <= Duration::from_secs(1));
+        }
+    }
+
+    #[test]
+    fn should_reject_rate_limits_that_never_refill() {
+        let invalid = [
+            RateLimit {
+                per_second: 0.0,
+                ..RateLimit::default()
+            },
+            RateLimit {
+                per_second: f64::INFINITY,
+                ..RateLimit::default()
+            },
+            RateLimit {
+                burst_duration: Duration::ZERO,
+                ..RateLimit::default()
+            },
+        ];
+
+        for limit in invalid {
+            assert!(matches!(
+                limit.validate(),
+                Err(Error::InvalidConfiguration(_))
+            ));
+        }
+        assert!(RateLimit::default().validate().is_ok());
+    }
+
+    #[tokio::test]
+    async fn should_adjust_limits_from_rate_limit_headers() {
+        let limiter = RateLimiter::new(RateLimit::default());
+
+        limiter
+            .update_from_response(
+                StatusCode::OK,
+                &headers(&[
+                    ("x-ratelimit-limit-per-second", "5"),
+                    ("x-ratelimit-limit-burst", "10"),
+                ]),
+            )
+            .await;
+
+        let expected = RateLimit {
+            per_second: 5.0,
+            burst: 10,
+            burst_duration: Duration::from_secs(60),
+        };
+
+        assert_eq!(expected, limiter.limit().await);
+    }
+}
@@ -0,0 +1,99 @@
+use super::{Error, ErrorCode, ErrorData, SpaceTradersClient};
+use crate::status::ServerStatus;
+
+#[derive(Debug, PartialEq)]
+pub enum ResetDetected {
+    ResetDateChanged { previous: String, current: String },
+    TokenRejected(ErrorData),
+}
+
+impl ResetDetected {
+    pub(crate) fn from_api_error(error: ErrorData) -> Result<ResetDetected, ErrorData> {
+        match error.code {
+            ErrorCode::TokenResetDateMismatchError | ErrorCode::AgentNotExistsError => {
+                Ok(ResetDetected::TokenRejected(error))
+            }
+            _ => Err(error),
+        }
+    }
+}
+
+impl SpaceTradersClient {
+    pub async fn detect_reset(
+        &self,
+        known_reset_date: &str,
+    ) -> Result<Option<ResetDetected>, Error> {
+        let status = ServerStatus::get(self).await?;
+
+        if status.reset_date == known_reset_date {
+            Ok(None)
+        } else {
+            Ok(Some(ResetDetected::ResetDateChanged {
+                previous: known_reset_date.to_string(),
+                current: status.reset_date,
+            }))
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use mock_server::{MockServerBuilder, RequestMethod};
+
+    use super::*;
+    use crate::string;
+
+    #[tokio::test]
+    async fn should_detect_a_reset_from_the_status_reset_date() {
+        let mock_server =
+            MockServerBuilder::mock_once(RequestMethod::Get, "", 200, None, None::<&()>).await;
+
+        let client = SpaceTradersClient::with_url(&mock_server.url(), None);
+
+        let actual = client.detect_reset("2024-05-26").await.unwrap();
+
+        let expected = Some(ResetDetected::ResetDateChanged {
+            previous: string!("2024-05-26"),
+            current: string!("2024-06-09"),
+        });
+
+        assert_eq!(expected, actual);
+    }
+
+    #[tokio::test]
+    async fn should_not_detect_a_reset_when_the_reset_date_is_unchanged() {
+        let mock_server =
+            MockServerBuilder::mock_once(RequestMethod::Get, "", 200, None, None::<&()>).await;
+
+        let client = SpaceTradersClient::with_url(&mock_server.url(), None);
+
+        let actual = client.detect_reset("2024-06-09").await.unwrap();
+
+        assert_eq!(None, actual);
+    }
+
+    #[tokio::test]
+    async fn should_detect_a_reset_when_the_token_is_rejected() {
+        let mock_server = MockServerBuilder::mock_once(
+            RequestMethod::Get,
+            "my/agent",
+            401,
+            Some(string!("token")),
+            None::<&()>,
+        )
+        .await;
+
+        let client = SpaceTradersClient::with_url(&mock_server.url(), Some(string!("token")));
+
+        let actual = client
+            .get::<(), serde_json::Value>("my/agent", None, reqwest::StatusCode::OK)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(
+            actual,
+            Error::ResetDetected(ResetDetected::TokenRejected(_))
+        ));
+        assert_eq!(Some(&ErrorCode::TokenResetDateMismatchError), actual.code());
+    }
+}
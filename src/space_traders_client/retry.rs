@@ -0,0 +1,129 @@
+use std::time::Duration;
+
+use reqwest::{Method, StatusCode};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    pub max_attempts: u32,
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+    pub jitter: bool,
+    pub retry_statuses: Vec<StatusCode>,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(10),
+            jitter: true,
+            retry_statuses: vec![
+                StatusCode::TOO_MANY_REQUESTS,
+                StatusCode::INTERNAL_SERVER_ERROR,
+                StatusCode::BAD_GATEWAY,
+                StatusCode::SERVICE_UNAVAILABLE,
+                StatusCode::GATEWAY_TIMEOUT,
+            ],
+        }
+    }
+}
+
+impl RetryPolicy {
+    pub fn none() -> Self {
+        RetryPolicy {
+            max_attempts: 1,
+            ..RetryPolicy::default()
+        }
+    }
+
+    pub fn should_retry_status(&self, method: &Method, status: StatusCode) -> bool {
+        if !self.retry_statuses.contains(&status) {
+            return false;
+        }
+
+        // A 429 is rejected before the request is processed, so it is the only status that
+        // is safe to repeat for a non-idempotent request.
+        is_idempotent(method) || status == StatusCode::TOO_MANY_REQUESTS
+    }
+
+    pub fn should_retry_error(&self, method: &Method, error: &reqwest::Error) -> bool {
+        if error.is_connect() {
+            return true;
+        }
+
+        is_idempotent(method) && (error.is_timeout() || error.is_request())
+    }
+
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let exponential = self
+            .initial_backoff
+            .saturating_mul(2u32.saturating_pow(attempt.saturating_sub(1)));
+        let capped = exponential.min(self.max_backoff);
+
+        if self.jitter {
+            capped.mul_f64(rand::random_range(0.5..=1.0))
+        } else {
+            capped
+        }
+    }
+}
+
+fn is_idempotent(method: &Method) -> bool {
+    matches!(
+        *method,
+        Method::GET | Method::HEAD | Method::PUT | Method::DELETE | Method::OPTIONS
+    )
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    fn no_jitter() -> RetryPolicy {
+        RetryPolicy {
+            jitter: false,
+            ..RetryPolicy::default()
+        }
+    }
+
+    #[test]
+    fn backoff_should_grow_exponentially_up_to_the_max() {
+        let policy = RetryPolicy {
+            max_backoff: Duration::from_secs(3),
+            ..no_jitter()
+        };
+
+        assert_eq!(Duration::from_millis(500), policy.backoff(1));
+        assert_eq!(Duration::from_millis(1000), policy.backoff(2));
+        assert_eq!(Duration::from_millis(2000), policy.backoff(3));
+        assert_eq!(Duration::from_millis(3000), policy.backoff(4));
+    }
+
+    #[test]
+    fn jittered_backoff_should_stay_within_half_and_full_delay() {
+        let policy = RetryPolicy::default();
+
+        for _ in 0..100 {
+            let backoff = policy.backoff(2);
+            assert!(backoff >= Duration::from_millis(500));
+            assert!(backoff <= Duration::from_millis(1000));
+        }
+    }
+
+    #[test]
+    fn get_should_be_retried_on_server_errors() {
+        let policy = RetryPolicy::default();
+
+        assert!(policy.should_retry_status(&Method::GET, StatusCode::BAD_GATEWAY));
+        assert!(!policy.should_retry_status(&Method::GET, StatusCode::NOT_FOUND));
+    }
+
+    #[test]
+    fn post_should_only_be_retried_when_the_request_was_not_processed() {
+        let policy = RetryPolicy::default();
+
+        assert!(!policy.should_retry_status(&Method::POST, StatusCode::BAD_GATEWAY));
+        assert!(policy.should_retry_status(&Method::POST, StatusCode::TOO_MANY_REQUESTS));
+    }
+}
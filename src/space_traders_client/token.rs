@@ -0,0 +1,171 @@
+use std::{fmt, str::FromStr};
+
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use serde_derive::Deserialize;
+
+use super::Error;
+
+const ACCOUNT_TOKEN_SUBJECT: &str = "account-token";
+const AGENT_TOKEN_SUBJECT: &str = "agent-token";
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct TokenClaims {
+    pub identifier: String,
+    pub version: Option<String>,
+    pub reset_date: Option<String>,
+    pub iat: i64,
+    pub sub: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TokenKind {
+    Account,
+    Agent,
+    Unknown(String),
+}
+
+// Claims are decoded without verifying the signature, they are only a local hint and the server
+// remains the authority on whether a token is valid.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Token {
+    raw: String,
+    claims: TokenClaims,
+}
+
+impl Token {
+    pub fn parse(raw: &str) -> Result<Token, Error> {
+        let payload = raw
+            .split('.')
+            .nth(1)
+            .ok_or_else(|| Error::InvalidToken(String::from("Token is not a JWT")))?;
+
+        let decoded = URL_SAFE_NO_PAD
+            .decode(payload.trim_end_matches('='))
+            .map_err(|e| Error::InvalidToken(e.to_string()))?;
+
+        let claims =
+            serde_json::from_slice(&decoded).map_err(|e| Error::InvalidToken(e.to_string()))?;
+
+        Ok(Token {
+            raw: raw.to_string(),
+            claims,
+        })
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.raw
+    }
+
+    pub fn claims(&self) -> &TokenClaims {
+        &self.claims
+    }
+
+    pub fn identifier(&self) -> &str {
+        &self.claims.identifier
+    }
+
+    pub fn reset_date(&self) -> Option<&str> {
+        self.claims.reset_date.as_deref()
+    }
+
+    pub fn issued_at(&self) -> i64 {
+        self.claims.iat
+    }
+
+    pub fn kind(&self) -> TokenKind {
+        match self.claims.sub.as_str() {
+            ACCOUNT_TOKEN_SUBJECT => TokenKind::Account,
+            AGENT_TOKEN_SUBJECT => TokenKind::Agent,
+            other => TokenKind::Unknown(other.to_string()),
+        }
+    }
+
+    pub fn is_account_token(&self) -> bool {
+        self.kind() == TokenKind::Account
+    }
+
+    pub fn is_agent_token(&self) -> bool {
+        self.kind() == TokenKind::Agent
+    }
+
+    // Reset dates are ISO dates, so comparing them as strings orders them chronologically.
+    // Account tokens carry no reset date and survive resets.
+    pub fn predates_reset(&self, current_reset_date: &str) -> bool {
+        match self.reset_date() {
+            Some(reset_date) => reset_date < current_reset_date,
+            None => false,
+        }
+    }
+}
+
+impl FromStr for Token {
+    type Err = Error;
+
+    fn from_str(raw: &str) -> Result<Self, Self::Err> {
+        Token::parse(raw)
+    }
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.raw)
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use crate::{
+        account::tests::{account_token, agent_token},
+        string,
+    };
+
+    #[test]
+    fn should_decode_agent_token_claims() {
+        let actual = Token::parse(&agent_token()).unwrap();
+
+        let expected = TokenClaims {
+            identifier: string!("BADGER"),
+            version: Some(string!("v2.3.0")),
+            reset_date: Some(string!("2025-05-25")),
+            iat: 1748558862,
+            sub: string!("agent-token"),
+        };
+
+        assert_eq!(&expected, actual.claims());
+        assert_eq!(TokenKind::Agent, actual.kind());
+        assert_eq!(agent_token(), actual.as_str());
+    }
+
+    #[test]
+    fn should_decode_account_token_claims() {
+        let actual: Token = account_token().parse().unwrap();
+
+        assert!(actual.is_account_token());
+        assert_eq!("c4dietz@gmail.com", actual.identifier());
+        assert_eq!(None, actual.reset_date());
+        assert_eq!(1748555989, actual.issued_at());
+    }
+
+    #[test]
+    fn agent_token_should_predate_a_later_reset() {
+        let token = Token::parse(&agent_token()).unwrap();
+
+        assert!(token.predates_reset("2025-06-08"));
+        assert!(!token.predates_reset("2025-05-25"));
+    }
+
+    #[test]
+    fn account_token_should_not_predate_a_reset() {
+        let token = Token::parse(&account_token()).unwrap();
+
+        assert!(!token.predates_reset("2025-06-08"));
+    }
+
+    #[test]
+    fn should_reject_a_token_that_is_not_a_jwt() {
+        let actual = Token::parse("some_token").unwrap_err();
+
+        assert!(matches!(actual, Error::InvalidToken(_)));
+    }
+}
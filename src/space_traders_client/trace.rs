@@ -0,0 +1,128 @@
+use std::future::Future;
+
+use super::{ApiRequest, ApiResponse, Error};
+
+#[cfg(feature = "tracing")]
+pub(crate) struct CallSpan {
+    span: tracing::Span,
+    start: tokio::time::Instant,
+}
+
+#[cfg(feature = "tracing")]
+impl CallSpan {
+    pub(crate) fn new(request: &ApiRequest) -> Self {
+        let span = tracing::info_span!(
+            "space_traders_request",
+            method = %request.method,
+            endpoint = %request.endpoint,
+            system = tracing::field::Empty,
+            waypoint = tracing::field::Empty,
+            ship = tracing::field::Empty,
+            status = tracing::field::Empty,
+            latency_ms = tracing::field::Empty,
+            request_id = tracing::field::Empty,
+            error_code = tracing::field::Empty,
+        );
+
+        for (field, collection) in [
+            ("system", "systems"),
+            ("waypoint", "waypoints"),
+            ("ship", "ships"),
+        ] {
+            if let Some(symbol) = path_symbol(&request.endpoint, collection) {
+                span.record(field, symbol);
+            }
+        }
+
+        CallSpan {
+            span,
+            start: tokio::time::Instant::now(),
+        }
+    }
+
+    pub(crate) fn instrument<F: Future>(&self, future: F) -> impl Future<Output = F::Output> {
+        tracing::Instrument::instrument(future, self.span.clone())
+    }
+
+    pub(crate) fn record_response(&self, response: &ApiResponse) {
+        self.span.record("status", response.status.as_u16());
+    }
+
+    pub(crate) fn record_result<R>(&self, result: &Result<R, Error>) {
+        self.span
+            .record("latency_ms", self.start.elapsed().as_millis() as u64);
+
+        match result {
+            Ok(_) => tracing::debug!(parent: &self.span, "request succeeded"),
+            Err(Error::Api(error))
+            | Err(Error::ResetDetected(super::ResetDetected::TokenRejected(error))) => {
+                self.span
+                    .record("error_code", tracing::field::debug(&error.code));
+                if let Some(request_id) = &error.request_id {
+                    self.span.record("request_id", request_id.as_str());
+                }
+                tracing::warn!(parent: &self.span, message = %error.message, "request failed");
+            }
+            Err(error) => tracing::warn!(parent: &self.span, %error, "request failed"),
+        }
+    }
+}
+
+#[cfg(feature = "tracing")]
+pub(crate) fn retry(attempt: u32) {
+    tracing::debug!(attempt, "retrying request");
+}
+
+#[cfg(feature = "tracing")]
+fn path_symbol<'a>(endpoint: &'a str, collection: &str) -> Option<&'a str> {
+    let mut segments = endpoint.split('/');
+    segments.find(|segment| *segment == collection)?;
+    segments.next()
+}
+
+#[cfg(not(feature = "tracing"))]
+pub(crate) struct CallSpan;
+
+#[cfg(not(feature = "tracing"))]
+impl CallSpan {
+    pub(crate) fn new(_request: &ApiRequest) -> Self {
+        CallSpan
+    }
+
+    pub(crate) fn instrument<F: Future>(&self, future: F) -> F {
+        future
+    }
+
+    pub(crate) fn record_response(&self, _response: &ApiResponse) {}
+
+    pub(crate) fn record_result<R>(&self, _result: &Result<R, Error>) {}
+}
+
+#[cfg(not(feature = "tracing"))]
+pub(crate) fn retry(_attempt: u32) {}
+
+#[cfg(all(test, feature = "tracing"))]
+pub mod tests {
+    use super::*;
+
+    #[test]
+    fn should_find_the_ship_symbol_in_a_fleet_endpoint() {
+        assert_eq!(
+            Some("BADGER-1"),
+            path_symbol("my/ships/BADGER-1/navigate", "ships")
+        );
+    }
+
+    #[test]
+    fn should_find_the_waypoint_symbol_in_a_system_endpoint() {
+        assert_eq!(
+            Some("X1-MH3-A1"),
+            path_symbol("systems/X1-MH3/waypoints/X1-MH3-A1/market", "waypoints")
+        );
+    }
+
+    #[test]
+    fn should_not_find_a_symbol_for_a_collection_endpoint() {
+        assert_eq!(None, path_symbol("my/ships", "ships"));
+    }
+}
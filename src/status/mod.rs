@@ -0,0 +1,208 @@
+use reqwest::StatusCode;
+use serde_derive::Deserialize;
+
+use crate::space_traders_client::{Error, ErrorCode, SpaceTradersClient};
+
+#[derive(Debug, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ServerStatus {
+    pub status: String,
+    pub version: String,
+    pub reset_date: String,
+    pub description: String,
+    pub stats: Stats,
+    pub health: Option<Health>,
+    pub leaderboards: Leaderboards,
+    pub server_resets: ServerResets,
+    pub announcements: Vec<Announcement>,
+    pub links: Vec<Link>,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Stats {
+    pub accounts: Option<i64>,
+    pub agents: i64,
+    pub ships: i64,
+    pub systems: i64,
+    pub waypoints: i64,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Health {
+    pub last_market_update: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Leaderboards {
+    pub most_credits: Vec<MostCredits>,
+    pub most_submitted_charts: Vec<MostSubmittedCharts>,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MostCredits {
+    pub agent_symbol: String,
+    pub credits: i64,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MostSubmittedCharts {
+    pub agent_symbol: String,
+    pub chart_count: i64,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ServerResets {
+    pub next: String,
+    pub frequency: String,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Announcement {
+    pub title: String,
+    pub body: String,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Link {
+    pub name: String,
+    pub url: String,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ErrorCodeDefinition {
+    pub code: u16,
+    pub name: String,
+}
+
+impl ErrorCodeDefinition {
+    pub fn error_code(&self) -> ErrorCode {
+        ErrorCode::from(self.code)
+    }
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ErrorCodesResponse {
+    error_codes: Vec<ErrorCodeDefinition>,
+}
+
+impl ServerStatus {
+    pub async fn get(client: &SpaceTradersClient) -> Result<ServerStatus, Error> {
+        client.get::<(), _>("", None, StatusCode::OK).await
+    }
+
+    pub async fn list_error_codes(
+        client: &SpaceTradersClient,
+    ) -> Result<Vec<ErrorCodeDefinition>, Error> {
+        let response: ErrorCodesResponse = client
+            .get::<(), _>("error-codes", None, StatusCode::OK)
+            .await?;
+
+        Ok(response.error_codes)
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use mock_server::{MockServerBuilder, RequestMethod};
+
+    use super::*;
+    use crate::string;
+
+    fn camel_case_name(code: &ErrorCode) -> String {
+        let name = format!("{:?}", code);
+        let mut chars = name.chars();
+        match chars.next() {
+            Some(first) => first.to_lowercase().chain(chars).collect(),
+            None => name,
+        }
+    }
+
+    #[tokio::test]
+    async fn should_get_server_status() {
+        let mock_server =
+            MockServerBuilder::mock_once(RequestMethod::Get, "", 200, None, None::<&()>).await;
+
+        let client = SpaceTradersClient::with_url(&mock_server.url(), None);
+
+        let actual = ServerStatus::get(&client).await.unwrap();
+
+        assert_eq!("2024-06-09", actual.reset_date);
+        assert_eq!(
+            ServerResets {
+                next: string!("2024-06-23T16:00:00.000Z"),
+                frequency: string!("fortnightly"),
+            },
+            actual.server_resets
+        );
+        assert_eq!(
+            MostCredits {
+                agent_symbol: string!("BADGER"),
+                credits: 12489632,
+            },
+            actual.leaderboards.most_credits[0]
+        );
+        assert_eq!(
+            MostSubmittedCharts {
+                agent_symbol: string!("FERRET"),
+                chart_count: 1204,
+            },
+            actual.leaderboards.most_submitted_charts[0]
+        );
+        assert_eq!(
+            vec![Announcement {
+                title: string!("Server Resets"),
+                body: string!("We will be resetting the server every two weeks."),
+            }],
+            actual.announcements
+        );
+        assert_eq!(2, actual.links.len());
+        assert_eq!(2130, actual.stats.agents);
+    }
+
+    #[tokio::test]
+    async fn local_error_codes_should_match_the_published_list() {
+        let mock_server =
+            MockServerBuilder::mock_once(RequestMethod::Get, "error-codes", 200, None, None::<&()>)
+                .await;
+
+        let client = SpaceTradersClient::with_url(&mock_server.url(), None);
+
+        let published = ServerStatus::list_error_codes(&client).await.unwrap();
+
+        let mismatched: Vec<&ErrorCodeDefinition> = published
+            .iter()
+            .filter(|definition| camel_case_name(&definition.error_code()) != definition.name)
+            .collect();
+
+        let unpublished: Vec<&ErrorCode> = ErrorCode::ALL
+            .iter()
+            .filter(|code| **code != ErrorCode::RateLimitError)
+            .filter(|code| {
+                !published
+                    .iter()
+                    .any(|definition| definition.error_code() == **code)
+            })
+            .collect();
+
+        assert!(
+            mismatched.is_empty(),
+            "Unknown or renamed: {:?}",
+            mismatched
+        );
+        assert!(
+            unpublished.is_empty(),
+            "No longer published: {:?}",
+            unpublished
+        );
+    }
+}
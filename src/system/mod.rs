@@ -1,10 +1,12 @@
 use std::sync::Arc;
 
+use futures::{Stream, TryStreamExt};
 use serde_derive::{Deserialize, Serialize};
 
 use crate::{
     faction::Factions,
-    space_traders_client::{Error, SpaceTradersClient},
+    space_traders_client::{Error, Page, SpaceTradersClient, MAX_PAGE_LIMIT},
+    string,
     system::waypoint::{
         Waypoint, WaypointData, WaypointOrbital, WaypointTraitSymbol, WaypointType,
     },
@@ -26,7 +28,7 @@ pub struct SystemData {
     pub factions: Vec<SystemFaction>,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum SystemType {
     NeutronStar,
@@ -39,6 +41,8 @@ pub enum SystemType {
     Hypergiant,
     Nebula,
     Unstable,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 #[derive(Debug, Clone, PartialEq, Deserialize)]
@@ -68,16 +72,6 @@ struct SystemResponse {
     data: SystemData,
 }
 
-#[derive(Debug, PartialEq, Deserialize)]
-struct ListSystemResponse {
-    data: Vec<SystemData>,
-}
-
-#[derive(Deserialize)]
-struct WaypointResponse {
-    data: Vec<WaypointData>,
-}
-
 #[derive(Serialize)]
 struct ListWayPointsParams {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -86,30 +80,24 @@ struct ListWayPointsParams {
     pub r#trait: Option<WaypointTraitSymbol>,
 }
 
-#[derive(Serialize)]
-struct ListSystemParams {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub page: Option<u8>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub limit: Option<u8>,
-}
-
 impl System {
-    pub async fn list_systems(
+    pub fn list_systems(
         client: &Arc<SpaceTradersClient>,
-        page: Option<u8>,
-        limit: Option<u8>,
-    ) -> Result<Vec<System>, Error> {
-        let params = ListSystemParams { page, limit };
-        let response: ListSystemResponse = client
-            .get("systems", Some(&params), reqwest::StatusCode::OK)
-            .await?;
+    ) -> impl Stream<Item = Result<System, Error>> + '_ {
+        client
+            .paginate(string!("systems"), None::<()>, MAX_PAGE_LIMIT)
+            .map_ok(|data| System::with_data(client.clone(), data))
+    }
+
+    pub async fn list_systems_page(
+        client: &Arc<SpaceTradersClient>,
+        page: u32,
+        limit: u32,
+    ) -> Result<Page<System>, Error> {
+        let response: Page<SystemData> =
+            client.get_page("systems", None::<&()>, page, limit).await?;
 
-        Ok(response
-            .data
-            .into_iter()
-            .map(|data| System::with_data(client.clone(), data))
-            .collect())
+        Ok(response.map(|data| System::with_data(client.clone(), data)))
     }
 
     pub async fn get_system_data(
@@ -135,37 +123,31 @@ impl System {
         Ok(System::with_data(client, data))
     }
 
-    pub async fn list_system_waypoints(
-        client: &Arc<SpaceTradersClient>,
+    pub fn list_system_waypoints<'a>(
+        client: &'a Arc<SpaceTradersClient>,
         symbol: &str,
         waypoint_type: Option<WaypointType>,
         waypoint_trait: Option<WaypointTraitSymbol>,
-    ) -> Result<Vec<Waypoint>, Error> {
+    ) -> impl Stream<Item = Result<Waypoint, Error>> + 'a {
         let query_params = ListWayPointsParams {
             r#type: waypoint_type,
             r#trait: waypoint_trait,
         };
 
-        let response: WaypointResponse = client
-            .get(
-                &format!("systems/{}/waypoints", symbol),
-                Some(&query_params),
-                reqwest::StatusCode::OK,
+        client
+            .paginate::<_, WaypointData>(
+                format!("systems/{}/waypoints", symbol),
+                Some(query_params),
+                MAX_PAGE_LIMIT,
             )
-            .await?;
-
-        Ok(response
-            .data
-            .into_iter()
-            .map(|data| Waypoint::with_data(client.clone(), data))
-            .collect())
+            .map_ok(|data| Waypoint::with_data(client.clone(), data))
     }
 }
 
 impl System {
     pub fn new(client: Arc<SpaceTradersClient>, symbol: &str) -> Self {
         System {
-            client: client,
+            client,
             data: None,
             symbol: symbol.into(),
         }
@@ -198,6 +180,7 @@ impl System {
         waypoint_trait: Option<WaypointTraitSymbol>,
     ) -> Result<Vec<Waypoint>, Error> {
         System::list_system_waypoints(&self.client, &self.symbol, waypoint_type, waypoint_trait)
+            .try_collect()
             .await
     }
 }
@@ -206,10 +189,11 @@ impl System {
 pub mod tests {
     use std::sync::Arc;
 
+    use futures::TryStreamExt;
     use mock_server::{MockServerBuilder, RequestMethod};
 
     use crate::{
-        space_traders_client::SpaceTradersClient,
+        space_traders_client::{Meta, Page, SpaceTradersClient},
         string,
         system::{
             waypoint::{
@@ -330,6 +314,20 @@ pub mod tests {
         }
     }
 
+    fn some_white_dwarf() -> SystemData {
+        SystemData {
+            symbol: string!("X1-QV47"),
+            sector_symbol: string!("X1"),
+            constellation: Some(string!("Zhang")),
+            name: Some(string!("Tau")),
+            r#type: SystemType::WhiteDwarf,
+            x: 1290,
+            y: 311,
+            waypoints: vec![],
+            factions: vec![],
+        }
+    }
+
     #[tokio::test]
     async fn should_list_systems() {
         let mock_server = MockServerBuilder::mock_once(
@@ -337,15 +335,13 @@ pub mod tests {
             "systems",
             200,
             None,
-            Some(&[("page", 1), ("limit", 2)]),
+            Some(&[("page", 1), ("limit", 20)]),
         )
         .await;
 
         let client = Arc::new(SpaceTradersClient::with_url(&mock_server.url(), None));
 
-        let actual = System::list_systems(&client, Some(1), Some(2))
-            .await
-            .unwrap();
+        let actual: Vec<System> = System::list_systems(&client).try_collect().await.unwrap();
 
         let expected = vec![
             System::with_data(client.clone(), some_neutron_star()),
@@ -355,6 +351,71 @@ pub mod tests {
         assert_eq!(expected, actual);
     }
 
+    #[tokio::test]
+    async fn should_list_a_page_of_systems() {
+        let mock_server = MockServerBuilder::mock_once(
+            RequestMethod::Get,
+            "systems",
+            200,
+            None,
+            Some(&[("page", 1), ("limit", 2)]),
+        )
+        .await;
+
+        let client = Arc::new(SpaceTradersClient::with_url(&mock_server.url(), None));
+
+        let actual = System::list_systems_page(&client, 1, 2).await.unwrap();
+
+        let expected = Page {
+            data: vec![
+                System::with_data(client.clone(), some_neutron_star()),
+                System::with_data(client.clone(), some_blue_star()),
+            ],
+            meta: Meta {
+                total: 3,
+                page: 1,
+                limit: 2,
+            },
+        };
+
+        assert_eq!(expected, actual);
+    }
+
+    #[tokio::test]
+    async fn should_walk_every_page_of_systems() {
+        let mock_server = MockServerBuilder::new()
+            .await
+            .mock_response(
+                RequestMethod::Get,
+                "systems",
+                200,
+                None,
+                Some(&[("page", 1), ("limit", 2)]),
+            )
+            .await
+            .mock_response(
+                RequestMethod::Get,
+                "systems",
+                200,
+                None,
+                Some(&[("page", 2), ("limit", 2)]),
+            )
+            .await
+            .build();
+
+        let client = SpaceTradersClient::with_url(&mock_server.url(), None);
+
+        let actual: Vec<SystemData> = client
+            .paginate(string!("systems"), None::<()>, 2)
+            .try_collect()
+            .await
+            .unwrap();
+
+        let expected = vec![some_neutron_star(), some_blue_star(), some_white_dwarf()];
+
+        assert_eq!(expected, actual);
+    }
+
     #[tokio::test]
     async fn should_get_system_data() {
         let mock_server = MockServerBuilder::mock_once(
@@ -419,18 +480,19 @@ pub mod tests {
 
     #[tokio::test]
     async fn should_list_waypoints() {
-        let mock_server = MockServerBuilder::mock_once::<serde_json::Value>(
+        let mock_server = MockServerBuilder::mock_once(
             RequestMethod::Get,
             "systems/X1-MH3/waypoints",
             200,
             None,
-            None,
+            Some(&[("page", "1"), ("limit", "20")]),
         )
         .await;
 
         let client = Arc::new(SpaceTradersClient::with_url(&mock_server.url(), None));
 
-        let actual = System::list_system_waypoints(&client, "X1-MH3", None, None)
+        let actual: Vec<Waypoint> = System::list_system_waypoints(&client, "X1-MH3", None, None)
+            .try_collect()
             .await
             .unwrap();
 
@@ -447,12 +509,12 @@ pub mod tests {
 
     #[tokio::test]
     async fn should_list_waypoints_with_an_object() {
-        let mock_server = MockServerBuilder::mock_once::<serde_json::Value>(
+        let mock_server = MockServerBuilder::mock_once(
             RequestMethod::Get,
             "systems/X1-MH3/waypoints",
             200,
             None,
-            None,
+            Some(&[("page", "1"), ("limit", "20")]),
         )
         .await;
 
@@ -480,7 +542,7 @@ pub mod tests {
             "systems/X1-MH3/waypoints",
             200,
             None,
-            Some(&[("type", "PLANET")]),
+            Some(&[("type", "PLANET"), ("page", "1"), ("limit", "20")]),
         )
         .await;
 
@@ -505,7 +567,7 @@ pub mod tests {
             "systems/X1-MH3/waypoints",
             200,
             None,
-            Some(&[("trait", "SHIPYARD")]),
+            Some(&[("trait", "SHIPYARD"), ("page", "1"), ("limit", "20")]),
         )
         .await;
 
@@ -578,4 +640,14 @@ pub mod tests {
 
         assert_eq!(expected, actual);
     }
+
+    #[test]
+    fn unknown_system_type_should_round_trip() {
+        let json_str = r#""PULSAR""#;
+
+        let actual: SystemType = serde_json::from_str(json_str).unwrap();
+
+        assert_eq!(SystemType::Unknown(string!("PULSAR")), actual);
+        assert_eq!(json_str, serde_json::to_string(&actual).unwrap());
+    }
 }
@@ -1,6 +1,9 @@
-use serde_derive::Deserialize;
+use serde_derive::{Deserialize, Serialize};
+
+pub use crate::ship::TradeSymbol;
 
 #[derive(Debug, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct Market {
     pub symbol: String,
     pub exports: Vec<TradeGood>,
@@ -10,22 +13,26 @@ pub struct Market {
     pub trade_goods: Option<Vec<MarketTradeGood>>,
 }
 
-#[derive(Debug, PartialEq, Deserialize)]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum TransactionType {
     Purchase,
     Sell,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
-#[derive(Debug, PartialEq, Deserialize)]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum TradeType {
     Export,
     Import,
     Exchange,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
-#[derive(Debug, PartialEq, Deserialize)]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum SupplyLevel {
     Scarce,
@@ -33,15 +40,19 @@ pub enum SupplyLevel {
     Moderate,
     High,
     Abundant,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
-#[derive(Debug, PartialEq, Deserialize)]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum ActivityLevel {
     Weak,
     Growing,
     Strong,
     Restricted,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 #[derive(Debug, PartialEq, Deserialize)]
@@ -52,10 +63,12 @@ pub struct TradeGood {
 }
 
 #[derive(Debug, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct MarketTransaction {
     pub waypoint_symbol: String,
     pub ship_symbol: String,
     pub trade_symbol: TradeSymbol,
+    #[serde(rename = "type")]
     pub transaction_type: TransactionType,
     pub units: i32,
     pub price_per_unit: i32,
@@ -64,8 +77,10 @@ pub struct MarketTransaction {
 }
 
 #[derive(Debug, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct MarketTradeGood {
     pub symbol: TradeSymbol,
+    #[serde(rename = "type")]
     pub trade_type: TradeType,
     pub trade_volume: i32,
     pub supply: SupplyLevel,
@@ -74,162 +89,15 @@ pub struct MarketTradeGood {
     pub sell_price: i32,
 }
 
-#[derive(Debug, PartialEq, Deserialize)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum TradeSymbol {
-    PreciousStones,
-    QuartzSand,
-    SiliconCrystals,
-    AmmoniaIce,
-    LiquidHydrogen,
-    LiquidNitrogen,
-    IceWater,
-    ExoticMatter,
-    AdvancedCircuitry,
-    GravitonEmitters,
-    Iron,
-    IronOre,
-    Copper,
-    CopperOre,
-    Aluminum,
-    AluminumOre,
-    Silver,
-    SilverOre,
-    Gold,
-    GoldOre,
-    Platinum,
-    PlatinumOre,
-    Diamonds,
-    Uranite,
-    UraniteOre,
-    Meritium,
-    MeritiumOre,
-    Hydrocarbon,
-    Antimatter,
-    FabMats,
-    Fertilizers,
-    Fabrics,
-    Food,
-    Jewelry,
-    Machinery,
-    Firearms,
-    AssaultRifles,
-    MilitaryEquipment,
-    Explosives,
-    LabInstruments,
-    Ammunition,
-    Electronics,
-    ShipPlating,
-    ShipParts,
-    Equipment,
-    Fuel,
-    Medicine,
-    Drugs,
-    Clothing,
-    Microprocessors,
-    Plastics,
-    Polynucleotides,
-    Biocomposites,
-    QuantumStabilizers,
-    Nanobots,
-    AiMainframes,
-    QuantumDrives,
-    RoboticDrones,
-    CyberImplants,
-    GeneTherapeutics,
-    NeuralChips,
-    MoodRegulators,
-    ViralAgents,
-    MicroFusionGenerators,
-    Supergrains,
-    LaserRifles,
-    Holographics,
-    ShipSalvage,
-    RelicTech,
-    NovelLifeforms,
-    BotanicalSpecimens,
-    CulturalArtifacts,
-    FrameProbe,
-    FrameDrone,
-    FrameInterceptor,
-    FrameRacer,
-    FrameFighter,
-    FrameFrigate,
-    FrameShuttle,
-    FrameExplorer,
-    FrameMiner,
-    FrameLightFreighter,
-    FrameHeavyFreighter,
-    FrameTransport,
-    FrameDestroyer,
-    FrameCruiser,
-    FrameCarrier,
-    FrameBulkFreighter,
-    ReactorSolarI,
-    ReactorFusionI,
-    ReactorFissionI,
-    ReactorChemicalI,
-    ReactorAntimatterI,
-    EngineImpulseDriveI,
-    EngineIonDriveI,
-    EngineIonDriveII,
-    EngineHyperDriveI,
-    ModuleMineralProcessorI,
-    ModuleGasProcessorI,
-    ModuleCargoHoldI,
-    ModuleCargoHoldII,
-    ModuleCargoHoldIII,
-    ModuleCrewQuartersI,
-    ModuleEnvoyQuartersI,
-    ModulePassengerCabinI,
-    ModuleMicroRefineryI,
-    ModuleScienceLabI,
-    ModuleJumpDriveI,
-    ModuleJumpDriveII,
-    ModuleJumpDriveIII,
-    ModuleWarpDriveI,
-    ModuleWarpDriveII,
-    ModuleWarpDriveIII,
-    ModuleShieldGeneratorI,
-    ModuleShieldGeneratorII,
-    ModuleOreRefineryI,
-    ModuleFuelRefineryI,
-    MountGasSiphonI,
-    MountGasSiphonII,
-    MountGasSiphonIII,
-    MountSurveyorI,
-    MountSurveyorII,
-    MountSurveyorIII,
-    MountSensorArrayI,
-    MountSensorArrayII,
-    MountSensorArrayIII,
-    MountMiningLaserI,
-    MountMiningLaserII,
-    MountMiningLaserIII,
-    MountLaserCannonI,
-    MountMissileLauncherI,
-    MountTurretI,
-    ShipProbe,
-    ShipMiningDrone,
-    ShipSiphonDrone,
-    ShipInterceptor,
-    ShipLightHauler,
-    ShipCommandFrigate,
-    ShipExplorer,
-    ShipHeavyFreighter,
-    ShipLightShuttle,
-    ShipOreHound,
-    ShipRefiningFreighter,
-    ShipSurveyor,
-    ShipBulkFreighter,
-}
-
 #[cfg(test)]
 pub mod tests {
     use crate::{
         string,
         system::waypoint::{
-            market::{Market, TradeGood, TradeSymbol},
+            market::{
+                ActivityLevel, Market, MarketTradeGood, SupplyLevel, TradeGood, TradeSymbol,
+                TradeType, TransactionType,
+            },
             MarketResponse,
         },
     };
@@ -300,4 +168,72 @@ pub mod tests {
 
         assert_eq!(expected, actual);
     }
+
+    #[test]
+    fn trade_good_type_should_be_deserializable() {
+        let json_str = r#"
+        {
+          "symbol": "IRON_ORE",
+          "type": "EXPORT",
+          "tradeVolume": 20,
+          "supply": "MODERATE",
+          "activity": "GROWING",
+          "purchasePrice": 50,
+          "sellPrice": 45
+        }"#;
+
+        let actual: MarketTradeGood = serde_json::from_str(json_str).unwrap();
+
+        assert_eq!(TradeType::Export, actual.trade_type);
+    }
+
+    #[test]
+    fn unknown_trade_symbol_should_round_trip() {
+        let json_str = r#""DARK_MATTER""#;
+
+        let actual: TradeSymbol = serde_json::from_str(json_str).unwrap();
+
+        assert_eq!(TradeSymbol::Unknown(string!("DARK_MATTER")), actual);
+        assert_eq!(json_str, serde_json::to_string(&actual).unwrap());
+    }
+
+    #[test]
+    fn unknown_transaction_type_should_round_trip() {
+        let json_str = r#""BARTER""#;
+
+        let actual: TransactionType = serde_json::from_str(json_str).unwrap();
+
+        assert_eq!(TransactionType::Unknown(string!("BARTER")), actual);
+        assert_eq!(json_str, serde_json::to_string(&actual).unwrap());
+    }
+
+    #[test]
+    fn unknown_trade_type_should_round_trip() {
+        let json_str = r#""SMUGGLE""#;
+
+        let actual: TradeType = serde_json::from_str(json_str).unwrap();
+
+        assert_eq!(TradeType::Unknown(string!("SMUGGLE")), actual);
+        assert_eq!(json_str, serde_json::to_string(&actual).unwrap());
+    }
+
+    #[test]
+    fn unknown_supply_level_should_round_trip() {
+        let json_str = r#""OVERFLOWING""#;
+
+        let actual: SupplyLevel = serde_json::from_str(json_str).unwrap();
+
+        assert_eq!(SupplyLevel::Unknown(string!("OVERFLOWING")), actual);
+        assert_eq!(json_str, serde_json::to_string(&actual).unwrap());
+    }
+
+    #[test]
+    fn unknown_activity_level_should_round_trip() {
+        let json_str = r#""DORMANT""#;
+
+        let actual: ActivityLevel = serde_json::from_str(json_str).unwrap();
+
+        assert_eq!(ActivityLevel::Unknown(string!("DORMANT")), actual);
+        assert_eq!(json_str, serde_json::to_string(&actual).unwrap());
+    }
 }
@@ -78,6 +78,8 @@ pub enum WaypointType {
     GravityWell,
     ArtificialGravityWell,
     FuelStation,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -152,9 +154,11 @@ pub enum WaypointTraitSymbol {
     UnstableComposition,
     HollowedInterior,
     Stripped,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum WaypointModifierSymbol {
     Stripped,
@@ -162,6 +166,8 @@ pub enum WaypointModifierSymbol {
     RadiationLeak,
     CriticalLimit,
     CivilUnrest,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 #[derive(Debug, PartialEq)]
@@ -211,7 +217,7 @@ impl Waypoint {
         Ok(Waypoint {
             symbol: symbol.to_string(),
             data: Some(Waypoint::get_waypoint_data(&client, system_symbol, symbol).await?),
-            client: client,
+            client,
         })
     }
 
@@ -260,7 +266,7 @@ impl Waypoint {
     pub fn with_data(client: Arc<SpaceTradersClient>, data: WaypointData) -> Self {
         Waypoint {
             symbol: data.symbol.clone(),
-            client: client,
+            client,
             data: Some(data),
         }
     }
@@ -1025,4 +1031,40 @@ pub mod tests {
 
         assert_eq!(expected, actual);
     }
+
+    #[test]
+    fn unknown_waypoint_type_should_round_trip() {
+        let json_str = r#""WORMHOLE""#;
+
+        let actual: WaypointType = serde_json::from_str(json_str).unwrap();
+
+        assert_eq!(WaypointType::Unknown(string!("WORMHOLE")), actual);
+        assert_eq!(json_str, serde_json::to_string(&actual).unwrap());
+    }
+
+    #[test]
+    fn unknown_waypoint_trait_symbol_should_round_trip() {
+        let json_str = r#""ANCIENT_RUINS""#;
+
+        let actual: WaypointTraitSymbol = serde_json::from_str(json_str).unwrap();
+
+        assert_eq!(
+            WaypointTraitSymbol::Unknown(string!("ANCIENT_RUINS")),
+            actual
+        );
+        assert_eq!(json_str, serde_json::to_string(&actual).unwrap());
+    }
+
+    #[test]
+    fn unknown_waypoint_modifier_symbol_should_round_trip() {
+        let json_str = r#""SOLAR_STORM""#;
+
+        let actual: WaypointModifierSymbol = serde_json::from_str(json_str).unwrap();
+
+        assert_eq!(
+            WaypointModifierSymbol::Unknown(string!("SOLAR_STORM")),
+            actual
+        );
+        assert_eq!(json_str, serde_json::to_string(&actual).unwrap());
+    }
 }
@@ -1,7 +1,9 @@
-use serde_derive::Deserialize;
+use serde_derive::{Deserialize, Serialize};
 
 use crate::{
+    agent::Agent,
     ship::{Crew, Engine, Frame, Module, Mount, Reactor},
+    space_traders_client::{Error, ErrorCode},
     system::waypoint::market::{ActivityLevel, SupplyLevel},
 };
 
@@ -17,6 +19,7 @@ pub struct Shipyard {
 }
 
 #[derive(Debug, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct ShipyardShip {
     pub r#type: ShipType,
     pub name: String,
@@ -32,7 +35,7 @@ pub struct ShipyardShip {
     pub crew: Crew,
 }
 
-#[derive(Debug, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum ShipType {
     #[serde(rename = "SHIP_PROBE")]
@@ -61,6 +64,8 @@ pub enum ShipType {
     Surveyor,
     #[serde(rename = "SHIP_BULK_FREIGHTER")]
     BulkFreighter,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 #[derive(Debug, PartialEq, Deserialize)]
@@ -69,6 +74,7 @@ pub struct ShipTypeWrapper {
 }
 
 #[derive(Debug, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct ShipyardTransaction {
     pub waypoint_symbol: String,
     pub ship_symbol: Option<String>,
@@ -78,16 +84,86 @@ pub struct ShipyardTransaction {
     pub timestamp: String,
 }
 
+impl Shipyard {
+    pub async fn purchase_ship(
+        &self,
+        agent: &mut Agent,
+        ship_type: ShipType,
+    ) -> Result<ShipyardTransaction, Error> {
+        if !self.ship_types.iter().any(|t| t.r#type == ship_type) {
+            return Err(Error::Precondition {
+                code: ErrorCode::ShipNotAvailableForPurchaseError,
+                message: format!("{:?} is not sold at {}", ship_type, self.symbol),
+            });
+        }
+
+        // Prices are only listed while one of our ships is at the shipyard.
+        let listing = self
+            .ships
+            .iter()
+            .flatten()
+            .find(|ship| ship.r#type == ship_type);
+
+        if let Some(listing) = listing {
+            if listing.purchase_price as i64 > agent.data.credits {
+                return Err(Error::Precondition {
+                    code: ErrorCode::PurchaseShipCreditsError,
+                    message: format!(
+                        "{:?} costs {} but only {} credits are available",
+                        ship_type, listing.purchase_price, agent.data.credits
+                    ),
+                });
+            }
+        }
+
+        agent.purchase_ship(ship_type, &self.symbol).await
+    }
+}
+
 #[cfg(test)]
 pub mod tests {
+    use mock_server::{MockServerBuilder, RequestMethod};
+
     use crate::{
+        account::tests::some_registration_response_data,
+        agent::Agent,
+        ship::tests::some_other_ship,
+        space_traders_client::{Error, ErrorCode, SpaceTradersClient},
         string,
         system::waypoint::{
-            shipyard::{ShipType, ShipTypeWrapper, Shipyard},
+            market::{ActivityLevel, SupplyLevel},
+            shipyard::{ShipType, ShipTypeWrapper, Shipyard, ShipyardShip},
             ShipyardResponse,
         },
     };
 
+    fn some_probe_listing(purchase_price: i32) -> ShipyardShip {
+        let probe = some_other_ship();
+
+        ShipyardShip {
+            r#type: ShipType::Probe,
+            name: string!("Probe"),
+            description: string!("A small, unmanned spacecraft used for exploration."),
+            supply: SupplyLevel::Moderate,
+            activity: ActivityLevel::Growing,
+            purchase_price,
+            frame: probe.frame,
+            reactor: probe.reactor,
+            engine: probe.engine,
+            modules: probe.modules,
+            mounts: probe.mounts,
+            crew: probe.crew,
+        }
+    }
+
+    fn some_shipyard_at_headquarters(purchase_price: i32) -> Shipyard {
+        Shipyard {
+            symbol: string!("X1-RC42-H53"),
+            ships: Some(vec![some_probe_listing(purchase_price)]),
+            ..some_shipyard()
+        }
+    }
+
     pub fn some_shipyard() -> Shipyard {
         Shipyard {
             symbol: string!("X1-MH3-A2"),
@@ -140,4 +216,73 @@ pub mod tests {
 
         assert_eq!(expected, actual);
     }
+
+    #[tokio::test]
+    async fn should_not_purchase_a_ship_the_agent_cannot_afford() {
+        let mut agent = Agent::from_registration_data(
+            &SpaceTradersClient::with_url("http://127.0.0.1:1", None),
+            some_registration_response_data(),
+        );
+
+        let actual = some_shipyard_at_headquarters(200000)
+            .purchase_ship(&mut agent, ShipType::Probe)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(actual, Error::Precondition { .. }));
+        assert_eq!(Some(&ErrorCode::PurchaseShipCreditsError), actual.code());
+    }
+
+    #[tokio::test]
+    async fn should_not_purchase_a_ship_the_shipyard_does_not_sell() {
+        let mut agent = Agent::from_registration_data(
+            &SpaceTradersClient::with_url("http://127.0.0.1:1", None),
+            some_registration_response_data(),
+        );
+
+        let actual = some_shipyard()
+            .purchase_ship(&mut agent, ShipType::OreHound)
+            .await
+            .unwrap_err();
+
+        assert_eq!(
+            Some(&ErrorCode::ShipNotAvailableForPurchaseError),
+            actual.code()
+        );
+    }
+
+    #[tokio::test]
+    async fn should_purchase_an_affordable_ship() {
+        let mock_server = MockServerBuilder::mock_once::<serde_json::Value>(
+            RequestMethod::Post,
+            "my/ships",
+            201,
+            None,
+            None,
+        )
+        .await;
+
+        let mut agent = Agent::from_registration_data(
+            &SpaceTradersClient::with_url(&mock_server.url(), None),
+            some_registration_response_data(),
+        );
+
+        let actual = some_shipyard_at_headquarters(25000)
+            .purchase_ship(&mut agent, ShipType::Probe)
+            .await
+            .unwrap();
+
+        assert_eq!(25000, actual.price);
+        assert_eq!(150000, agent.data.credits);
+    }
+
+    #[test]
+    fn unknown_ship_type_should_round_trip() {
+        let json_str = r#""SHIP_CARRIER""#;
+
+        let actual: ShipType = serde_json::from_str(json_str).unwrap();
+
+        assert_eq!(ShipType::Unknown(string!("SHIP_CARRIER")), actual);
+        assert_eq!(json_str, serde_json::to_string(&actual).unwrap());
+    }
 }
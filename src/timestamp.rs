@@ -0,0 +1,72 @@
+use std::{convert::Infallible, fmt, str::FromStr};
+
+use serde_derive::{Deserialize, Serialize};
+
+// Dates are kept exactly as the server sent them, the `chrono` feature adds a parsed view.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Timestamp(String);
+
+impl Timestamp {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    #[cfg(feature = "chrono")]
+    pub fn to_date_time(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        chrono::DateTime::parse_from_rfc3339(&self.0)
+            .ok()
+            .map(|date_time| date_time.to_utc())
+    }
+}
+
+impl FromStr for Timestamp {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Timestamp(s.to_string()))
+    }
+}
+
+impl From<String> for Timestamp {
+    fn from(value: String) -> Self {
+        Timestamp(value)
+    }
+}
+
+impl fmt::Display for Timestamp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    #[test]
+    fn should_round_trip_the_server_string() {
+        let json_str = r#""2025-06-05T22:47:42.900Z""#;
+
+        let actual: Timestamp = serde_json::from_str(json_str).unwrap();
+
+        assert_eq!("2025-06-05T22:47:42.900Z", actual.as_str());
+        assert_eq!(json_str, serde_json::to_string(&actual).unwrap());
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn should_parse_into_a_date_time() {
+        use chrono::{TimeZone, Utc};
+
+        let actual: Timestamp = "2025-06-05T22:47:42.900Z".parse().unwrap();
+        let unparsable: Timestamp = "soon".parse().unwrap();
+
+        assert_eq!(
+            Utc.with_ymd_and_hms(2025, 6, 5, 22, 47, 42).unwrap()
+                + chrono::TimeDelta::milliseconds(900),
+            actual.to_date_time().unwrap()
+        );
+        assert_eq!(None, unparsable.to_date_time());
+    }
+}
@@ -4,7 +4,9 @@ use dotenv::dotenv;
 use space_traders_sdk::account::Account;
 use space_traders_sdk::account::RegistrationRequest;
 use space_traders_sdk::faction::Factions;
+use space_traders_sdk::space_traders_client::ErrorCode;
 use space_traders_sdk::space_traders_client::SpaceTradersClient;
+use space_traders_sdk::status::ServerStatus;
 use space_traders_sdk::string;
 use space_traders_sdk::system::waypoint::WaypointTraitSymbol;
 use space_traders_sdk::system::waypoint::WaypointType;
@@ -70,3 +72,18 @@ async fn can_get_waypoints_from_a_system_and_get_info_for_those_way_points() {
 
     println!("A Market: {:?}\n", market);
 }
+
+#[ignore]
+#[tokio::test]
+async fn every_published_error_code_is_known() {
+    let client = SpaceTradersClient::new(None);
+
+    let unknown: Vec<_> = ServerStatus::list_error_codes(&client)
+        .await
+        .unwrap()
+        .into_iter()
+        .filter(|definition| matches!(definition.error_code(), ErrorCode::Unknown(_)))
+        .collect();
+
+    assert!(unknown.is_empty(), "Unknown error codes: {:?}", unknown);
+}